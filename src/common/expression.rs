@@ -1,55 +1,73 @@
+use crate::common::scalar::Scalar;
 use std::cmp::Ordering;
 use std::fmt;
+use std::iter::Sum;
 use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
 /// A linear expression stored as a sorted sparse vector.
 /// Invariants:
 /// 1. `terms` is always sorted by Variable T.
-/// 2. `terms` never contains coefficients with abs() < tolerance (effectively zero).
+/// 2. `terms` never contains zero coefficients.
+///
+/// Arithmetic only prunes exact zeros; `prune` drops coefficients up to a tolerance,
+/// as the model does with `SolverConfig::drop_tolerance`.
+///
+/// The coefficients are of type `S`; see [`LinearExpr`] for the `f64` expressions used
+/// to build models.
 #[derive(Debug, Clone)]
-pub struct LinearExpr<T: ExprVariable> {
-    pub terms: Vec<(T, f64)>,
-    pub constant: f64,
+pub struct GenericLinearExpr<T: ExprVariable, S: Scalar> {
+    pub terms: Vec<(T, S)>,
+    pub constant: S,
 }
 
+/// A linear expression with `f64` coefficients, as used to build models.
+///
+/// The scalar type is fixed so that expressions such as `LinearExpr::from(x) + y`
+/// infer without annotations.
+pub type LinearExpr<T> = GenericLinearExpr<T, f64>;
+
 /// Trait for types that can be used as variables in a linear expression.
 pub trait ExprVariable: Clone + Copy + Eq + Ord + fmt::Display {}
 
-impl<T: ExprVariable> LinearExpr<T> {
-    const TOLERANCE: f64 = 1e-10;
+impl<T: ExprVariable, S: Scalar> Default for GenericLinearExpr<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
+impl<T: ExprVariable, S: Scalar> GenericLinearExpr<T, S> {
     pub fn new() -> Self {
         Self {
             terms: Vec::new(),
-            constant: 0.0,
+            constant: S::ZERO,
         }
     }
 
-    pub fn with_term(var: T, coefficient: f64) -> Self {
-        if coefficient.abs() < Self::TOLERANCE {
+    pub fn with_term(var: T, coefficient: S) -> Self {
+        if coefficient == S::ZERO {
             return Self::new();
         }
         Self {
             terms: vec![(var, coefficient)],
-            constant: 0.0,
+            constant: S::ZERO,
         }
     }
 
-    pub fn with_terms(mut terms: Vec<(T, f64)>) -> Self {
+    pub fn with_terms(mut terms: Vec<(T, S)>) -> Self {
         // 1. Sort by variable to enable O(N) merging later
-        terms.sort_by(|a, b| a.0.cmp(&b.0));
+        terms.sort_by_key(|a| a.0);
 
         // 2. Deduplicate (merge coefficients for same variable) and Filter Zeros
         let mut dedup_terms = Vec::with_capacity(terms.len());
         if !terms.is_empty() {
-            let mut current_var = terms[0].0.clone();
-            let mut current_coeff = terms[0].1;
+            let mut terms = terms.into_iter();
+            let (mut current_var, mut current_coeff) = terms.next().unwrap();
 
-            for (var, coeff) in terms.into_iter().skip(1) {
+            for (var, coeff) in terms {
                 if var == current_var {
                     current_coeff += coeff;
                 } else {
-                    if current_coeff.abs() >= Self::TOLERANCE {
+                    if current_coeff != S::ZERO {
                         dedup_terms.push((current_var, current_coeff));
                     }
                     current_var = var;
@@ -57,39 +75,44 @@ impl<T: ExprVariable> LinearExpr<T> {
                 }
             }
             // Push the last one
-            if current_coeff.abs() >= Self::TOLERANCE {
+            if current_coeff != S::ZERO {
                 dedup_terms.push((current_var, current_coeff));
             }
         }
 
         Self {
             terms: dedup_terms,
-            constant: 0.0,
+            constant: S::ZERO,
         }
     }
 
-    pub fn with_constant(constant: f64) -> Self {
+    pub fn with_constant(constant: S) -> Self {
         Self {
             terms: Vec::new(),
             constant,
         }
     }
 
-    pub fn with_terms_and_constant(terms: Vec<(T, f64)>, constant: f64) -> Self {
+    pub fn with_terms_and_constant(terms: Vec<(T, S)>, constant: S) -> Self {
         let mut expr = Self::with_terms(terms);
         expr.constant = constant;
         expr
     }
 
-    pub fn coefficient(&self, var: &T) -> f64 {
+    /// Removes the terms whose coefficient has a magnitude at or below `tolerance`.
+    pub fn prune(&mut self, tolerance: S) {
+        self.terms.retain(|(_, c)| c.abs() > tolerance);
+    }
+
+    pub fn coefficient(&self, var: &T) -> S {
         self.terms
             .binary_search_by(|(v, _)| v.cmp(var))
-            .map(|idx| self.terms[idx].1)
-            .unwrap_or(0.0)
+            .map(|idx| self.terms[idx].1.clone())
+            .unwrap_or(S::ZERO)
     }
 
-    pub fn add_term(&mut self, var: T, coefficient: f64) {
-        if coefficient.abs() < Self::TOLERANCE {
+    pub fn add_term(&mut self, var: T, coefficient: S) {
+        if coefficient == S::ZERO {
             return;
         }
 
@@ -97,7 +120,7 @@ impl<T: ExprVariable> LinearExpr<T> {
             Ok(idx) => {
                 self.terms[idx].1 += coefficient;
                 // Check if it became zero after addition
-                if self.terms[idx].1.abs() < Self::TOLERANCE {
+                if self.terms[idx].1 == S::ZERO {
                     self.terms.remove(idx);
                 }
             }
@@ -107,7 +130,17 @@ impl<T: ExprVariable> LinearExpr<T> {
         }
     }
 
-    pub fn remove_term(&mut self, var: &T) -> Option<f64> {
+    /// Evaluates the expression with the variable values given by `value`,
+    /// e.g. `expr.evaluate(|x| plan[x])` for an assignment stored in a map.
+    pub fn evaluate(&self, value: impl Fn(T) -> S) -> S {
+        self.terms
+            .iter()
+            .map(|(var, coefficient)| coefficient.clone() * value(*var))
+            .sum::<S>()
+            + self.constant.clone()
+    }
+
+    pub fn remove_term(&mut self, var: &T) -> Option<S> {
         if let Ok(idx) = self.terms.binary_search_by(|(v, _)| v.cmp(var)) {
             Some(self.terms.remove(idx).1)
         } else {
@@ -116,16 +149,16 @@ impl<T: ExprVariable> LinearExpr<T> {
     }
 
     pub fn add_expr(&mut self, other: &Self) {
-        self.add_scaled_expr(other, 1.0);
+        self.add_scaled_expr(other, S::ONE);
     }
 
     pub fn sub_expr(&mut self, other: &Self) {
-        self.add_scaled_expr(other, -1.0);
+        self.add_scaled_expr(other, -S::ONE);
     }
 
-    pub fn add_scaled_expr(&mut self, other: &Self, scale: f64) {
+    pub fn add_scaled_expr(&mut self, other: &Self, scale: S) {
         if other.terms.is_empty() {
-            self.constant += other.constant * scale;
+            self.constant += other.constant.clone() * scale;
             return;
         }
 
@@ -141,20 +174,20 @@ impl<T: ExprVariable> LinearExpr<T> {
 
             match var_self.cmp(var_other) {
                 Ordering::Less => {
-                    new_terms.push((var_self.clone(), *coeff_self));
+                    new_terms.push((*var_self, coeff_self.clone()));
                     i += 1;
                 }
                 Ordering::Greater => {
-                    let scaled_val = coeff_other * scale;
-                    if scaled_val.abs() > Self::TOLERANCE {
-                        new_terms.push((var_other.clone(), scaled_val));
+                    let scaled_val = coeff_other.clone() * scale.clone();
+                    if scaled_val != S::ZERO {
+                        new_terms.push((*var_other, scaled_val));
                     }
                     j += 1;
                 }
                 Ordering::Equal => {
-                    let new_coeff = *coeff_self + (coeff_other * scale);
-                    if new_coeff.abs() > Self::TOLERANCE {
-                        new_terms.push((var_self.clone(), new_coeff));
+                    let new_coeff = coeff_self.clone() + coeff_other.clone() * scale.clone();
+                    if new_coeff != S::ZERO {
+                        new_terms.push((*var_self, new_coeff));
                     }
                     i += 1;
                     j += 1;
@@ -170,32 +203,32 @@ impl<T: ExprVariable> LinearExpr<T> {
         // Append remaining from other
         while j < other.terms.len() {
             let (var, coeff) = &other.terms[j];
-            let scaled_val = coeff * scale;
-            if scaled_val.abs() > Self::TOLERANCE {
-                new_terms.push((var.clone(), scaled_val));
+            let scaled_val = coeff.clone() * scale.clone();
+            if scaled_val != S::ZERO {
+                new_terms.push((*var, scaled_val));
             }
             j += 1;
         }
 
         self.terms = new_terms;
-        self.constant += other.constant * scale;
+        self.constant += other.constant.clone() * scale;
     }
 
-    pub fn add_constant(&mut self, constant: f64) {
+    pub fn add_constant(&mut self, constant: S) {
         self.constant += constant;
     }
 
-    pub fn scale(&mut self, scalar: f64) {
-        if scalar.abs() < Self::TOLERANCE {
+    pub fn scale(&mut self, scalar: S) {
+        if scalar == S::ZERO {
             self.terms.clear();
-            self.constant = 0.0;
+            self.constant = S::ZERO;
             return;
         }
 
         // We might create zeros if the scalar is very small, so we must filter.
         self.terms.retain_mut(|(_, c)| {
-            *c *= scalar;
-            c.abs() > Self::TOLERANCE
+            *c *= scalar.clone();
+            *c != S::ZERO
         });
         self.constant *= scalar;
     }
@@ -203,13 +236,13 @@ impl<T: ExprVariable> LinearExpr<T> {
     pub fn replace_var_with_expr(
         &mut self,
         var: T,
-        replacement_expr: &LinearExpr<T>,
-    ) -> Option<f64> {
+        replacement_expr: &GenericLinearExpr<T, S>,
+    ) -> Option<S> {
         // 1. Remove the term (O(log N) + O(N) shift)
         if let Some(coefficient) = self.remove_term(&var) {
             // 2. Merge the new expression (O(N + M))
             // This replaces the old O(M * N) loop.
-            self.add_scaled_expr(replacement_expr, coefficient);
+            self.add_scaled_expr(replacement_expr, coefficient.clone());
             Some(coefficient)
         } else {
             None
@@ -223,30 +256,30 @@ impl<T: ExprVariable> LinearExpr<T> {
 
 // --- Conversions ---
 
-/// Implements `From<f64>` for `LinearExpr`.
-impl<T: ExprVariable> From<f64> for LinearExpr<T> {
-    fn from(constant: f64) -> Self {
-        LinearExpr::with_constant(constant)
+/// Implements `From<Scalar>` for `GenericLinearExpr`.
+impl<T: ExprVariable, S: Scalar> From<S> for GenericLinearExpr<T, S> {
+    fn from(constant: S) -> Self {
+        GenericLinearExpr::with_constant(constant)
     }
 }
 
 // --- Negation (-Expr) ---
 
 /// Implements `-Expr`
-impl<T: ExprVariable> Neg for LinearExpr<T> {
+impl<T: ExprVariable, S: Scalar> Neg for GenericLinearExpr<T, S> {
     type Output = Self;
     fn neg(mut self) -> Self {
-        self.scale(-1.0);
+        self.scale(-S::ONE);
         self
     }
 }
 
 /// Implements `-&Expr` (creates new Owned)
-impl<'a, T: ExprVariable> Neg for &'a LinearExpr<T> {
-    type Output = LinearExpr<T>;
-    fn neg(self) -> LinearExpr<T> {
+impl<T: ExprVariable, S: Scalar> Neg for &GenericLinearExpr<T, S> {
+    type Output = GenericLinearExpr<T, S>;
+    fn neg(self) -> GenericLinearExpr<T, S> {
         let mut new_expr = self.clone();
-        new_expr.scale(-1.0);
+        new_expr.scale(-S::ONE);
         new_expr
     }
 }
@@ -254,7 +287,7 @@ impl<'a, T: ExprVariable> Neg for &'a LinearExpr<T> {
 // --- Addition (Expr + X) ---
 
 /// Implements `Expr + Expr` (Reuse LHS)
-impl<T: ExprVariable> Add<LinearExpr<T>> for LinearExpr<T> {
+impl<T: ExprVariable, S: Scalar> Add<GenericLinearExpr<T, S>> for GenericLinearExpr<T, S> {
     type Output = Self;
     fn add(mut self, rhs: Self) -> Self {
         self.add_expr(&rhs);
@@ -263,36 +296,30 @@ impl<T: ExprVariable> Add<LinearExpr<T>> for LinearExpr<T> {
 }
 
 /// Implements `Expr + &Expr` (Reuse LHS)
-impl<'a, T: ExprVariable> Add<&'a LinearExpr<T>> for LinearExpr<T> {
+impl<'a, T: ExprVariable, S: Scalar> Add<&'a GenericLinearExpr<T, S>> for GenericLinearExpr<T, S> {
     type Output = Self;
-    fn add(mut self, rhs: &'a LinearExpr<T>) -> Self {
+    fn add(mut self, rhs: &'a GenericLinearExpr<T, S>) -> Self {
         self.add_expr(rhs);
         self
     }
 }
 
-/// Implements `Expr + Var`
-impl<T: ExprVariable> Add<T> for LinearExpr<T> {
+/// Implements `Expr + Scalar`
+///
+/// `Expr + Var` is implemented per variable type by `impl_expr_ops!`, so that it does
+/// not overlap with this impl.
+impl<T: ExprVariable, S: Scalar> Add<S> for GenericLinearExpr<T, S> {
     type Output = Self;
-    fn add(mut self, var: T) -> Self {
-        self.add_term(var, 1.0);
-        self
-    }
-}
-
-/// Implements `Expr + f64`
-impl<T: ExprVariable> Add<f64> for LinearExpr<T> {
-    type Output = Self;
-    fn add(mut self, constant: f64) -> Self {
+    fn add(mut self, constant: S) -> Self {
         self.add_constant(constant);
         self
     }
 }
 
-/// Implements `&Expr + f64` (creates new Owned)
-impl<'a, T: ExprVariable> Add<f64> for &'a LinearExpr<T> {
-    type Output = LinearExpr<T>;
-    fn add(self, constant: f64) -> LinearExpr<T> {
+/// Implements `&Expr + Scalar` (creates new Owned)
+impl<T: ExprVariable, S: Scalar> Add<S> for &GenericLinearExpr<T, S> {
+    type Output = GenericLinearExpr<T, S>;
+    fn add(self, constant: S) -> GenericLinearExpr<T, S> {
         let mut new_expr = self.clone();
         new_expr.add_constant(constant);
         new_expr
@@ -300,7 +327,7 @@ impl<'a, T: ExprVariable> Add<f64> for &'a LinearExpr<T> {
 }
 
 /// Implements `Expr += Expr`
-impl<T: ExprVariable> AddAssign for LinearExpr<T> {
+impl<T: ExprVariable, S: Scalar> AddAssign for GenericLinearExpr<T, S> {
     fn add_assign(&mut self, rhs: Self) {
         self.add_expr(&rhs);
     }
@@ -309,7 +336,7 @@ impl<T: ExprVariable> AddAssign for LinearExpr<T> {
 // --- Subtraction (Expr - X) ---
 
 /// Implements `Expr - Expr` (Reuse LHS)
-impl<T: ExprVariable> Sub<LinearExpr<T>> for LinearExpr<T> {
+impl<T: ExprVariable, S: Scalar> Sub<GenericLinearExpr<T, S>> for GenericLinearExpr<T, S> {
     type Output = Self;
     fn sub(mut self, rhs: Self) -> Self {
         self.sub_expr(&rhs);
@@ -318,36 +345,27 @@ impl<T: ExprVariable> Sub<LinearExpr<T>> for LinearExpr<T> {
 }
 
 /// Implements `Expr - &Expr` (Reuse LHS)
-impl<'a, T: ExprVariable> Sub<&'a LinearExpr<T>> for LinearExpr<T> {
+impl<'a, T: ExprVariable, S: Scalar> Sub<&'a GenericLinearExpr<T, S>> for GenericLinearExpr<T, S> {
     type Output = Self;
-    fn sub(mut self, rhs: &'a LinearExpr<T>) -> Self {
+    fn sub(mut self, rhs: &'a GenericLinearExpr<T, S>) -> Self {
         self.sub_expr(rhs);
         self
     }
 }
 
-/// Implements `Expr - Var`
-impl<T: ExprVariable> Sub<T> for LinearExpr<T> {
-    type Output = Self;
-    fn sub(mut self, var: T) -> Self {
-        self.add_term(var, -1.0);
-        self
-    }
-}
-
-/// Implements `Expr - f64`
-impl<T: ExprVariable> Sub<f64> for LinearExpr<T> {
+/// Implements `Expr - Scalar`
+impl<T: ExprVariable, S: Scalar> Sub<S> for GenericLinearExpr<T, S> {
     type Output = Self;
-    fn sub(mut self, constant: f64) -> Self {
+    fn sub(mut self, constant: S) -> Self {
         self.add_constant(-constant);
         self
     }
 }
 
-/// Implements `&Expr - f64` (creates new Owned)
-impl<'a, T: ExprVariable> Sub<f64> for &'a LinearExpr<T> {
-    type Output = LinearExpr<T>;
-    fn sub(self, constant: f64) -> LinearExpr<T> {
+/// Implements `&Expr - Scalar` (creates new Owned)
+impl<T: ExprVariable, S: Scalar> Sub<S> for &GenericLinearExpr<T, S> {
+    type Output = GenericLinearExpr<T, S>;
+    fn sub(self, constant: S) -> GenericLinearExpr<T, S> {
         let mut new_expr = self.clone();
         new_expr.add_constant(-constant);
         new_expr
@@ -355,54 +373,117 @@ impl<'a, T: ExprVariable> Sub<f64> for &'a LinearExpr<T> {
 }
 
 /// Implements `Expr -= Expr`
-impl<T: ExprVariable> SubAssign for LinearExpr<T> {
+impl<T: ExprVariable, S: Scalar> SubAssign for GenericLinearExpr<T, S> {
     fn sub_assign(&mut self, rhs: Self) {
         self.sub_expr(&rhs);
     }
 }
 
-// --- Multiplication (Expr * f64) ---
+// --- Multiplication (Expr * Scalar) ---
 
-/// Implements `Expr * f64`
-impl<T: ExprVariable> Mul<f64> for LinearExpr<T> {
+/// Implements `Expr * Scalar`
+impl<T: ExprVariable, S: Scalar> Mul<S> for GenericLinearExpr<T, S> {
     type Output = Self;
-    fn mul(mut self, scalar: f64) -> Self {
+    fn mul(mut self, scalar: S) -> Self {
         self.scale(scalar);
         self
     }
 }
 
-/// Implements `&Expr * f64` (creates new Owned)
-impl<'a, T: ExprVariable> Mul<f64> for &'a LinearExpr<T> {
-    type Output = LinearExpr<T>;
-    fn mul(self, scalar: f64) -> LinearExpr<T> {
+/// Implements `&Expr * Scalar` (creates new Owned)
+impl<T: ExprVariable, S: Scalar> Mul<S> for &GenericLinearExpr<T, S> {
+    type Output = GenericLinearExpr<T, S>;
+    fn mul(self, scalar: S) -> GenericLinearExpr<T, S> {
         let mut new_expr = self.clone();
         new_expr.scale(scalar);
         new_expr
     }
 }
 
-// --- Division (Expr / f64) ---
+// --- Division (Expr / Scalar) ---
 
-/// Implements `Expr / f64`
-impl<T: ExprVariable> Div<f64> for LinearExpr<T> {
+/// Implements `Expr / Scalar`
+impl<T: ExprVariable, S: Scalar> Div<S> for GenericLinearExpr<T, S> {
     type Output = Self;
-    fn div(mut self, scalar: f64) -> Self {
-        self.scale(1.0 / scalar);
+    fn div(mut self, scalar: S) -> Self {
+        self.scale(S::ONE / scalar);
         self
     }
 }
 
-/// Implements `&Expr / f64` (creates new Owned)
-impl<'a, T: ExprVariable> Div<f64> for &'a LinearExpr<T> {
-    type Output = LinearExpr<T>;
-    fn div(self, scalar: f64) -> LinearExpr<T> {
+/// Implements `&Expr / Scalar` (creates new Owned)
+impl<T: ExprVariable, S: Scalar> Div<S> for &GenericLinearExpr<T, S> {
+    type Output = GenericLinearExpr<T, S>;
+    fn div(self, scalar: S) -> GenericLinearExpr<T, S> {
         let mut new_expr = self.clone();
-        new_expr.scale(1.0 / scalar);
+        new_expr.scale(S::ONE / scalar);
         new_expr
     }
 }
 
+// --- Summation (Iterator::sum, collect) ---
+
+/// Sums expressions or variables, e.g. `quicksum(items.iter().map(|&(c, x)| c * x))`.
+///
+/// All terms are gathered first and sorted once, instead of merging every item into a
+/// growing expression.
+pub fn quicksum<T, S, E>(items: impl IntoIterator<Item = E>) -> GenericLinearExpr<T, S>
+where
+    T: ExprVariable,
+    S: Scalar,
+    E: Into<GenericLinearExpr<T, S>>,
+{
+    let mut terms = Vec::new();
+    let mut constant = S::ZERO;
+    for item in items {
+        let expr = item.into();
+        terms.extend(expr.terms);
+        constant += expr.constant;
+    }
+    GenericLinearExpr::with_terms_and_constant(terms, constant)
+}
+
+/// Implements `exprs.sum::<LinearExpr<_>>()`
+impl<T: ExprVariable, S: Scalar> Sum for GenericLinearExpr<T, S> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        quicksum(iter)
+    }
+}
+
+/// Implements `exprs.iter().sum::<LinearExpr<_>>()`
+impl<'a, T: ExprVariable, S: Scalar> Sum<&'a GenericLinearExpr<T, S>> for GenericLinearExpr<T, S> {
+    fn sum<I: Iterator<Item = &'a GenericLinearExpr<T, S>>>(iter: I) -> Self {
+        let mut terms = Vec::new();
+        let mut constant = S::ZERO;
+        for expr in iter {
+            terms.extend_from_slice(&expr.terms);
+            constant += expr.constant.clone();
+        }
+        GenericLinearExpr::with_terms_and_constant(terms, constant)
+    }
+}
+
+/// Implements `vars.sum::<LinearExpr<_>>()`
+impl<T: ExprVariable, S: Scalar> Sum<T> for GenericLinearExpr<T, S> {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        GenericLinearExpr::with_terms(iter.map(|var| (var, S::ONE)).collect())
+    }
+}
+
+/// Implements `terms.sum::<LinearExpr<_>>()` over `(variable, coefficient)` pairs
+impl<T: ExprVariable, S: Scalar> Sum<(T, S)> for GenericLinearExpr<T, S> {
+    fn sum<I: Iterator<Item = (T, S)>>(iter: I) -> Self {
+        GenericLinearExpr::with_terms(iter.collect())
+    }
+}
+
+/// Implements `terms.collect::<LinearExpr<_>>()` over `(variable, coefficient)` pairs
+impl<T: ExprVariable, S: Scalar> FromIterator<(T, S)> for GenericLinearExpr<T, S> {
+    fn from_iter<I: IntoIterator<Item = (T, S)>>(iter: I) -> Self {
+        GenericLinearExpr::with_terms(iter.into_iter().collect())
+    }
+}
+
 // ============================================================
 //  MACRO: Boilerplate for "Orphan Rule" Cases
 //  Handles interactions where `LinearExpr` is on the Right Hand Side.
@@ -410,15 +491,18 @@ impl<'a, T: ExprVariable> Div<f64> for &'a LinearExpr<T> {
 
 macro_rules! impl_expr_ops {
     ($var_type:ty) => {
-        use crate::common::expression::LinearExpr;
+        use crate::common::{
+            expression::{GenericLinearExpr, LinearExpr},
+            scalar::Scalar,
+        };
         use std::ops::{Add, Div, Mul, Neg, Sub};
 
         // --- 1. Variable Conversions ---
 
-        /// Implements `From<Var>` for `LinearExpr`
-        impl From<$var_type> for LinearExpr<$var_type> {
+        /// Implements `From<Var>` for `GenericLinearExpr`
+        impl<S: Scalar> From<$var_type> for GenericLinearExpr<$var_type, S> {
             fn from(var: $var_type) -> Self {
-                LinearExpr::with_term(var, 1.0)
+                GenericLinearExpr::with_term(var, S::ONE)
             }
         }
 
@@ -451,22 +535,40 @@ macro_rules! impl_expr_ops {
         // --- 3. Variable/Scalar -> Expression Interactions ---
         // (Where Expr is on the Right Hand Side)
 
+        /// Implements `Expr + Var`
+        impl<S: Scalar> Add<$var_type> for GenericLinearExpr<$var_type, S> {
+            type Output = Self;
+            fn add(mut self, var: $var_type) -> Self {
+                self.add_term(var, S::ONE);
+                self
+            }
+        }
+
+        /// Implements `Expr - Var`
+        impl<S: Scalar> Sub<$var_type> for GenericLinearExpr<$var_type, S> {
+            type Output = Self;
+            fn sub(mut self, var: $var_type) -> Self {
+                self.add_term(var, -S::ONE);
+                self
+            }
+        }
+
         /// Implements `Var + Expr`
-        impl Add<LinearExpr<$var_type>> for $var_type {
-            type Output = LinearExpr<$var_type>;
-            fn add(self, mut expr: LinearExpr<$var_type>) -> Self::Output {
-                expr.add_term(self, 1.0);
+        impl<S: Scalar> Add<GenericLinearExpr<$var_type, S>> for $var_type {
+            type Output = GenericLinearExpr<$var_type, S>;
+            fn add(self, mut expr: GenericLinearExpr<$var_type, S>) -> Self::Output {
+                expr.add_term(self, S::ONE);
                 expr
             }
         }
 
         /// Implements `Var - Expr`
-        impl Sub<LinearExpr<$var_type>> for $var_type {
-            type Output = LinearExpr<$var_type>;
-            fn sub(self, mut expr: LinearExpr<$var_type>) -> Self::Output {
+        impl<S: Scalar> Sub<GenericLinearExpr<$var_type, S>> for $var_type {
+            type Output = GenericLinearExpr<$var_type, S>;
+            fn sub(self, mut expr: GenericLinearExpr<$var_type, S>) -> Self::Output {
                 // var - expr => -expr + var
-                expr.scale(-1.0);
-                expr.add_term(self, 1.0);
+                expr.scale(-S::ONE);
+                expr.add_term(self, S::ONE);
                 expr
             }
         }
@@ -593,21 +695,23 @@ macro_rules! impl_expr_ops {
 
 macro_rules! impl_expr_display {
     ($var_type:ty) => {
-        impl fmt::Display for LinearExpr<$var_type> {
+        impl<S: $crate::common::scalar::Scalar> fmt::Display
+            for $crate::common::expression::GenericLinearExpr<$var_type, S>
+        {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 let mut first = true;
 
                 for (var, coefficient) in &self.terms {
-                    let coefficient = *coefficient;
+                    let coefficient = coefficient.clone();
 
                     // Skip zero coefficients
-                    if coefficient == 0.0 {
+                    if coefficient == S::ZERO {
                         continue;
                     }
 
                     // Print the sign if needed (based on first or not)
                     if !first {
-                        if coefficient > 0.0 {
+                        if coefficient > S::ZERO {
                             write!(f, " + ")?;
                         } else {
                             write!(f, " - ")?;
@@ -615,9 +719,11 @@ macro_rules! impl_expr_display {
                     }
 
                     // Formatting the coefficient (with limited precision for readability)
-                    let coefficient_str = match coefficient {
-                        1.0 => String::new(),
-                        -1.0 => {
+                    let is_one = coefficient == S::ONE;
+                    let is_minus_one = coefficient == -S::ONE;
+                    let coefficient_str = match () {
+                        _ if is_one => String::new(),
+                        _ if is_minus_one => {
                             if first {
                                 String::from("-")
                             } else {
@@ -635,7 +741,7 @@ macro_rules! impl_expr_display {
                     };
 
                     // If the coefficient is not 0 or 1 or -1, print the coefficient followed by a space and the variable
-                    if coefficient != 1.0 && coefficient != -1.0 {
+                    if !is_one && !is_minus_one {
                         write!(f, "{} ", coefficient_str)?;
                     } else {
                         write!(f, "{}", coefficient_str)?; // No space if it's just '1' or '-1'
@@ -648,9 +754,9 @@ macro_rules! impl_expr_display {
                 }
 
                 // Handle constant term
-                if self.constant != 0.0 || first {
+                if self.constant != S::ZERO || first {
                     if !first {
-                        if self.constant > 0.0 {
+                        if self.constant > S::ZERO {
                             write!(f, " + ")?;
                         } else {
                             write!(f, " - ")?;
@@ -660,9 +766,9 @@ macro_rules! impl_expr_display {
                         f,
                         "{:.2}",
                         if first {
-                            self.constant
+                            self.constant.clone()
                         } else {
-                            self.constant.abs()
+                            self.constant.clone().abs()
                         }
                     )?;
                 }
@@ -0,0 +1,109 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Numeric type used for the coefficients of linear expressions and by the simplex solver.
+///
+/// Implemented for `f64` (the default everywhere), `f32` and, with the `exact` feature,
+/// `BigRational`. Models are always built with `f64` coefficients;
+/// `Model::solve_with_precision` only converts them for the solve.
+pub trait Scalar:
+    Clone
+    + PartialOrd
+    + Default
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+    + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// The finest solver tolerance this type can honor.
+    const MIN_TOLERANCE: Self;
+
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(&self) -> f64;
+    fn abs(&self) -> Self;
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Converts a solver tolerance to this type, clamped to at least `MIN_TOLERANCE`.
+    fn tolerance(tolerance: f64) -> Self {
+        let tolerance = Self::from_f64(tolerance);
+        if tolerance < Self::MIN_TOLERANCE {
+            Self::MIN_TOLERANCE
+        } else {
+            tolerance
+        }
+    }
+}
+
+macro_rules! impl_scalar_for_float {
+    ($float:ty, $min_tolerance:expr) => {
+        impl Scalar for $float {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const MIN_TOLERANCE: Self = $min_tolerance;
+
+            fn from_f64(value: f64) -> Self {
+                value as $float
+            }
+
+            fn to_f64(&self) -> f64 {
+                *self as f64
+            }
+
+            fn abs(&self) -> Self {
+                <$float>::abs(*self)
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                <$float>::total_cmp(self, other)
+            }
+        }
+    };
+}
+
+impl_scalar_for_float!(f64, 1e-15);
+impl_scalar_for_float!(f32, 1e-6);
+
+/// Exact rational coefficients, e.g. `model.solve_with_precision::<BigRational>()`.
+///
+/// Model data is read through `to_rational`, and every tolerance is zero.
+#[cfg(feature = "exact")]
+impl Scalar for num_rational::BigRational {
+    const ZERO: Self =
+        num_rational::Ratio::new_raw(num_bigint::BigInt::ZERO, num_bigint::BigInt::ONE);
+    const ONE: Self =
+        num_rational::Ratio::new_raw(num_bigint::BigInt::ONE, num_bigint::BigInt::ONE);
+    const MIN_TOLERANCE: Self = Self::ZERO;
+
+    fn from_f64(value: f64) -> Self {
+        crate::solver::exact::rational::to_rational(value)
+    }
+
+    fn to_f64(&self) -> f64 {
+        num_traits::ToPrimitive::to_f64(self).unwrap_or(f64::NAN)
+    }
+
+    fn abs(&self) -> Self {
+        num_traits::Signed::abs(self)
+    }
+
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+
+    fn tolerance(_tolerance: f64) -> Self {
+        Self::ZERO
+    }
+}
@@ -1,3 +1,4 @@
+use crate::modeling::{constraint::ConstraintKey, sos::SosKey, variable::VariableKey};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -10,4 +11,80 @@ pub enum SolverError {
 
     #[error("Model has no variables")]
     NoVariables,
+
+    #[error("Variable {variable} has an invalid bound {value}")]
+    InvalidBound { variable: VariableKey, value: f64 },
+
+    #[error("Variable {variable} has a lower bound {lower} above its upper bound {upper}")]
+    InvertedBounds {
+        variable: VariableKey,
+        lower: f64,
+        upper: f64,
+    },
+
+    /// The bounds are inverted, infinite on both sides or NaN.
+    #[error("Range constraint {constraint} has invalid bounds {lower} <= lhs <= {upper}")]
+    InvalidRange {
+        constraint: ConstraintKey,
+        lower: f64,
+        upper: f64,
+    },
+
+    /// Like `InvalidRange`, reported by `TryConstraintBuilder::between` instead of adding
+    /// the constraint.
+    #[error("Invalid range bounds {lower} <= lhs <= {upper}")]
+    InvalidRangeBounds { lower: f64, upper: f64 },
+
+    /// `variable` is `None` when the constant term is not finite.
+    #[error(
+        "Constraint {constraint} has a non-finite coefficient {value} (variable: {variable:?})"
+    )]
+    InvalidConstraintCoefficient {
+        constraint: ConstraintKey,
+        variable: Option<VariableKey>,
+        value: f64,
+    },
+
+    /// `variable` is `None` when the constant term is not finite.
+    #[error("Objective has a non-finite coefficient {value} (variable: {variable:?})")]
+    InvalidObjectiveCoefficient {
+        variable: Option<VariableKey>,
+        value: f64,
+    },
+
+    #[error(
+        "Constraint {constraint} references {variable}, which is not a variable of this model"
+    )]
+    UnknownConstraintVariable {
+        constraint: ConstraintKey,
+        variable: VariableKey,
+    },
+
+    #[error("Objective references {variable}, which is not a variable of this model")]
+    UnknownObjectiveVariable { variable: VariableKey },
+
+    #[error("Expression references {variable}, which is not a variable of this model")]
+    UnknownVariable { variable: VariableKey },
+
+    #[error(
+        "Piecewise-linear function of {variable} needs at least two finite breakpoints with strictly increasing x values"
+    )]
+    InvalidBreakpoints { variable: VariableKey },
+
+    #[error("Special ordered set needs one finite weight per variable, all of them distinct")]
+    InvalidSosWeights,
+
+    #[error("Special ordered set lists {variable} more than once")]
+    DuplicateSosVariable { variable: VariableKey },
+
+    #[error(
+        "Special ordered set {set} references {variable}, which is not a variable of this model"
+    )]
+    UnknownSosVariable { set: SosKey, variable: VariableKey },
+
+    #[error("Indicator variable {variable} must be a binary variable of this model")]
+    InvalidIndicatorVariable { variable: VariableKey },
+
+    #[error("Indicator constraint needs finite bounds on {variable} to derive its big-M")]
+    UnboundedIndicator { variable: VariableKey },
 }
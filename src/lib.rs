@@ -56,13 +56,29 @@ pub mod modeling;
 pub mod prelude;
 
 // --- API Re-exports ---
-pub use crate::common::expression::LinearExpr;
+pub use crate::common::expression::{quicksum, GenericLinearExpr, LinearExpr};
+pub use crate::common::scalar::Scalar;
 
-pub use crate::modeling::constraint::{Constraint, ConstraintKey, ConstraintSense};
-pub use crate::modeling::model::Model;
+pub use crate::modeling::constraint::{
+    Constraint, ConstraintKey, ConstraintSense, TryConstraintBuilder,
+};
+pub use crate::modeling::indicator::{Indicator, IndicatorBuilder, IndicatorKey};
+pub use crate::modeling::model::{Model, ModelId};
 pub use crate::modeling::objective::{Objective, ObjectiveSense};
-pub use crate::modeling::variable::{Variable, VariableKey, VariableType};
+pub use crate::modeling::solution_check::{SolutionCheck, Violation};
+pub use crate::modeling::solution_writer::{SolutionFormat, SolutionWriter};
+pub use crate::modeling::sos::{SosKey, SosType, SpecialOrderedSet};
+pub use crate::modeling::variable::{
+    Variable, VariableKey, VariableType, Variables, VariablesIter,
+};
+pub use crate::modeling::variable_family::{Shape, VariableFamily, VariableIndex};
 
-pub use crate::solver::config::SolverConfig;
+pub use crate::solver::callback::{CallbackAction, SolverCallback, SolverEvent, SolverPhase};
+pub use crate::solver::cancellation::CancellationToken;
+pub use crate::solver::config::{SolverAlgorithm, SolverConfig};
+#[cfg(feature = "exact")]
+pub use crate::solver::exact::solution::ExactSolution;
 pub use crate::solver::solution::SolverSolution;
 pub use crate::solver::status::SolverStatus;
+#[cfg(feature = "exact")]
+pub use num_rational::BigRational;
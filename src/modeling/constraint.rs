@@ -1,6 +1,10 @@
-use crate::{common::expression::LinearExpr, modeling::variable::VariableKey};
+use crate::{
+    common::expression::LinearExpr,
+    error::SolverError,
+    modeling::{model::Model, name_index::NameIndex, variable::VariableKey},
+};
 use slotmap::{new_key_type, DenseSlotMap};
-use std::fmt;
+use std::{fmt, ops::RangeInclusive};
 
 new_key_type! {
     pub struct ConstraintKey;
@@ -17,6 +21,8 @@ pub enum ConstraintSense {
     LessEqual,
     GreaterEqual,
     Equal,
+    /// `lower <= LHS <= RHS`, where the right hand side is the constant upper bound.
+    Range,
 }
 
 #[derive(Debug, Clone)]
@@ -25,6 +31,8 @@ pub struct Constraint {
     lhs: LinearExpr<VariableKey>,
     sense: ConstraintSense,
     rhs: LinearExpr<VariableKey>,
+    /// Lower bound of the left hand side of a `Range` constraint.
+    lower: f64,
 }
 
 // Public Getters for Read-Only Access
@@ -34,6 +42,11 @@ impl Constraint {
         self.name.as_deref().unwrap_or("<unnamed>")
     }
 
+    /// Returns the name of the constraint, if one was set.
+    pub(crate) fn explicit_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     /// Returns the Left Hand Side expression.
     pub fn lhs(&self) -> &LinearExpr<VariableKey> {
         &self.lhs
@@ -44,10 +57,26 @@ impl Constraint {
         &self.rhs
     }
 
-    /// Returns the comparison sense (<=, >=, =).
+    /// Returns the comparison sense (<=, >=, =, range).
     pub fn sense(&self) -> ConstraintSense {
         self.sense
     }
+
+    /// Returns the lower and upper bound of the left hand side of a `Range` constraint.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        match self.sense {
+            ConstraintSense::Range => Some((self.lower, self.rhs.constant)),
+            _ => None,
+        }
+    }
+
+    /// Returns how far `lhs - rhs` may drop below zero: the width of a range, zero otherwise.
+    pub(crate) fn range_width(&self) -> f64 {
+        match self.sense {
+            ConstraintSense::Range => self.rhs.constant - self.lower,
+            _ => 0.0,
+        }
+    }
 }
 
 impl fmt::Display for Constraint {
@@ -56,8 +85,19 @@ impl fmt::Display for Constraint {
             ConstraintSense::LessEqual => "<=",
             ConstraintSense::GreaterEqual => ">=",
             ConstraintSense::Equal => "=",
+            ConstraintSense::Range => "<=",
         };
 
+        if let Some((lower, _)) = self.range() {
+            return write!(
+                f,
+                "Constraint({}: {} <= {} <= {})",
+                self.name(),
+                lower,
+                self.lhs,
+                self.rhs
+            );
+        }
         write!(
             f,
             "Constraint({}: {} {} {})",
@@ -74,19 +114,25 @@ impl fmt::Display for Constraint {
 /// A builder for creating and configuring a new constraint.
 pub struct ConstraintBuilder<'a> {
     arena: &'a mut DenseSlotMap<ConstraintKey, Constraint>,
+    names: &'a mut NameIndex<ConstraintKey>,
     lhs: LinearExpr<VariableKey>,
     name: Option<String>,
+    drop_tolerance: f64,
 }
 
 impl<'a> ConstraintBuilder<'a> {
     pub(crate) fn new(
         arena: &'a mut DenseSlotMap<ConstraintKey, Constraint>,
+        names: &'a mut NameIndex<ConstraintKey>,
         lhs: LinearExpr<VariableKey>,
+        drop_tolerance: f64,
     ) -> Self {
         Self {
             arena,
+            names,
             lhs,
             name: None,
+            drop_tolerance,
         }
     }
 
@@ -128,13 +174,142 @@ impl<'a> ConstraintBuilder<'a> {
         self.equal_to(rhs)
     }
 
+    /// Creates a Range constraint: `lower <= LHS <= upper`, with a single key and dual value.
+    ///
+    /// An infinite bound leaves a one-sided `<=` or `>=` constraint instead. Bounds that
+    /// do not form a range (`lower > upper`, infinite on both sides, or NaN) are kept as
+    /// given and reported by `Model::validate` as `SolverError::InvalidRange`.
+    pub fn between(self, lower: f64, upper: f64) -> ConstraintKey {
+        if lower == f64::NEG_INFINITY && upper.is_finite() {
+            self.less_than_or_equal(upper)
+        } else if upper == f64::INFINITY && lower.is_finite() {
+            self.greater_than_or_equal(lower)
+        } else {
+            self.finish_range(lower, upper)
+        }
+    }
+
+    /// Alias for `between` taking a range, e.g. `.range(2.0..=8.0)`.
+    pub fn range(self, range: RangeInclusive<f64>) -> ConstraintKey {
+        self.between(*range.start(), *range.end())
+    }
+
     fn finish(self, sense: ConstraintSense, rhs: LinearExpr<VariableKey>) -> ConstraintKey {
+        self.insert(sense, rhs, f64::NEG_INFINITY)
+    }
+
+    fn finish_range(self, lower: f64, upper: f64) -> ConstraintKey {
+        self.insert(ConstraintSense::Range, upper.into(), lower)
+    }
+
+    fn insert(
+        mut self,
+        sense: ConstraintSense,
+        mut rhs: LinearExpr<VariableKey>,
+        lower: f64,
+    ) -> ConstraintKey {
+        self.lhs.prune(self.drop_tolerance);
+        rhs.prune(self.drop_tolerance);
         let data = Constraint {
             name: self.name,
             lhs: self.lhs,
             sense,
             rhs,
+            lower,
         };
-        self.arena.insert(data)
+        let constr_key = self.arena.insert(data);
+        if let Some(name) = self.arena[constr_key].explicit_name() {
+            self.names.insert(name, constr_key);
+        }
+        constr_key
+    }
+}
+
+/// A builder for a constraint whose expressions are checked for variables of another
+/// model, as returned by `Model::try_add_constraint`.
+///
+/// Finishing it reports `SolverError::UnknownVariable` instead of adding the constraint
+/// when either side references a variable that does not belong to the model.
+pub struct TryConstraintBuilder<'a> {
+    model: &'a mut Model,
+    lhs: LinearExpr<VariableKey>,
+    name: Option<String>,
+}
+
+impl<'a> TryConstraintBuilder<'a> {
+    pub(crate) fn new(model: &'a mut Model, lhs: LinearExpr<VariableKey>) -> Self {
+        Self {
+            model,
+            lhs,
+            name: None,
+        }
+    }
+
+    /// Sets the name of the constraint.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Creates a Less Than or Equal constraint: `LHS <= RHS`.
+    pub fn le(self, rhs: impl Into<LinearExpr<VariableKey>>) -> Result<ConstraintKey, SolverError> {
+        self.finish(rhs.into(), |builder, rhs| builder.le(rhs))
+    }
+
+    /// Creates a Greater Than or Equal constraint: `LHS >= RHS`.
+    pub fn ge(self, rhs: impl Into<LinearExpr<VariableKey>>) -> Result<ConstraintKey, SolverError> {
+        self.finish(rhs.into(), |builder, rhs| builder.ge(rhs))
+    }
+
+    /// Creates an Equality constraint: `LHS == RHS`.
+    pub fn eq(self, rhs: impl Into<LinearExpr<VariableKey>>) -> Result<ConstraintKey, SolverError> {
+        self.finish(rhs.into(), |builder, rhs| builder.eq(rhs))
+    }
+
+    /// Creates a Range constraint: `lower <= LHS <= upper`, like `ConstraintBuilder::between`.
+    ///
+    /// Bounds that do not form a range are reported as `SolverError::InvalidRangeBounds`.
+    pub fn between(self, lower: f64, upper: f64) -> Result<ConstraintKey, SolverError> {
+        if !is_valid_range(lower, upper) {
+            return Err(SolverError::InvalidRangeBounds { lower, upper });
+        }
+        self.finish(LinearExpr::new(), |builder, _| {
+            builder.between(lower, upper)
+        })
+    }
+
+    /// Alias for `between` taking a range, e.g. `.range(2.0..=8.0)`.
+    pub fn range(self, range: RangeInclusive<f64>) -> Result<ConstraintKey, SolverError> {
+        self.between(*range.start(), *range.end())
+    }
+
+    fn finish(
+        self,
+        rhs: LinearExpr<VariableKey>,
+        add: impl FnOnce(ConstraintBuilder<'_>, LinearExpr<VariableKey>) -> ConstraintKey,
+    ) -> Result<ConstraintKey, SolverError> {
+        for expr in [&self.lhs, &rhs] {
+            self.model
+                .find_unknown_variable(expr)
+                .map_or(Ok(()), |variable| {
+                    Err(SolverError::UnknownVariable { variable })
+                })?;
+        }
+        let mut builder = self.model.add_constraint(self.lhs);
+        if let Some(name) = self.name {
+            builder = builder.name(name);
+        }
+        Ok(add(builder, rhs))
+    }
+}
+
+/// Returns true if `lower <= LHS <= upper` is a constraint: `lower <= upper`, with at most
+/// one infinite bound, on the side it bounds.
+pub(crate) fn is_valid_range(lower: f64, upper: f64) -> bool {
+    match (lower.is_finite(), upper.is_finite()) {
+        (true, true) => lower <= upper,
+        (true, false) => upper == f64::INFINITY,
+        (false, true) => lower == f64::NEG_INFINITY,
+        (false, false) => false,
     }
 }
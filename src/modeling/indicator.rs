@@ -0,0 +1,127 @@
+use crate::{
+    common::expression::LinearExpr,
+    error::SolverError,
+    modeling::{
+        constraint::{ConstraintKey, ConstraintSense},
+        model::Model,
+        variable::VariableKey,
+    },
+};
+use slotmap::new_key_type;
+use std::fmt;
+
+new_key_type! {
+    pub struct IndicatorKey;
+}
+
+impl fmt::Display for IndicatorKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "IndicatorKey({:?})", self.0)
+    }
+}
+
+/// An indicator constraint: a linear constraint that must hold when a binary variable
+/// takes its active value.
+///
+/// It is modeled by big-M rows, which are regular constraints of the model.
+#[derive(Debug, Clone)]
+pub struct Indicator {
+    binary: VariableKey,
+    active: bool,
+    constraints: Vec<ConstraintKey>,
+}
+
+impl Indicator {
+    pub(crate) fn new(binary: VariableKey, active: bool, constraints: Vec<ConstraintKey>) -> Self {
+        Self {
+            binary,
+            active,
+            constraints,
+        }
+    }
+
+    /// Returns the binary variable that switches the constraint on.
+    pub fn binary(&self) -> VariableKey {
+        self.binary
+    }
+
+    /// Returns the value of the binary variable for which the constraint holds.
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    /// Returns the big-M rows: one for `<=` and `>=`, two for `=`.
+    pub fn constraints(&self) -> &[ConstraintKey] {
+        &self.constraints
+    }
+}
+
+/// A builder for an indicator constraint, finished like a `ConstraintBuilder`.
+pub struct IndicatorBuilder<'a> {
+    model: &'a mut Model,
+    binary: VariableKey,
+    active: bool,
+    lhs: LinearExpr<VariableKey>,
+    name: Option<String>,
+}
+
+impl<'a> IndicatorBuilder<'a> {
+    pub(crate) fn new(
+        model: &'a mut Model,
+        binary: VariableKey,
+        active: bool,
+        lhs: LinearExpr<VariableKey>,
+    ) -> Self {
+        Self {
+            model,
+            binary,
+            active,
+            lhs,
+            name: None,
+        }
+    }
+
+    /// Sets the name of the big-M rows. The two rows of an `eq` indicator are named
+    /// `name[le]` and `name[ge]`, so each can be found by name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Finalizes the indicator constraint as `LHS <= RHS`.
+    pub fn le(self, rhs: impl Into<LinearExpr<VariableKey>>) -> Result<IndicatorKey, SolverError> {
+        self.finish(ConstraintSense::LessEqual, rhs.into())
+    }
+
+    /// Finalizes the indicator constraint as `LHS >= RHS`.
+    pub fn ge(self, rhs: impl Into<LinearExpr<VariableKey>>) -> Result<IndicatorKey, SolverError> {
+        self.finish(ConstraintSense::GreaterEqual, rhs.into())
+    }
+
+    /// Finalizes the indicator constraint as `LHS = RHS`.
+    pub fn eq(self, rhs: impl Into<LinearExpr<VariableKey>>) -> Result<IndicatorKey, SolverError> {
+        self.finish(ConstraintSense::Equal, rhs.into())
+    }
+
+    fn finish(
+        self,
+        sense: ConstraintSense,
+        rhs: LinearExpr<VariableKey>,
+    ) -> Result<IndicatorKey, SolverError> {
+        // Every row reads `expr <= 0` while the constraint is active
+        let difference = self.lhs - rhs;
+        let name = self.name;
+        let rows = match sense {
+            ConstraintSense::LessEqual => vec![(difference, name)],
+            ConstraintSense::GreaterEqual => vec![(-difference, name)],
+            _ => {
+                let suffixed = |suffix| name.as_ref().map(|name| format!("{}[{}]", name, suffix));
+                vec![
+                    (difference.clone(), suffixed("le")),
+                    (-difference, suffixed("ge")),
+                ]
+            }
+        };
+        self.model.insert_indicator(self.binary, self.active, rows)
+    }
+}
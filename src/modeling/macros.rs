@@ -0,0 +1,284 @@
+//! Declarative macros for writing objectives and constraints in mathematical notation.
+//!
+//! Both sides of a constraint are linear expressions of terms separated by `+` and `-`.
+//! A term is either a product such as `3*x` or `c[i]*x[i]`, or a sum over an iterator
+//! written `sum(i in 0..n) c[i]*x[i]`, whose body extends to the next `+` or `-`.
+//! Numeric literals are read as `f64`, so `3*x` needs no `3.0`. Parenthesize negated
+//! factors (`2*(-x)`) and sums whose body has several terms (`sum(i in I) (x[i] - y[i])`).
+//!
+//! Expressions of a few dozen written-out terms expand within the default recursion
+//! limit; longer ones are better written as a `sum`.
+
+/// Adds an objective or a constraint to a model.
+///
+/// ```
+/// # use rustplex::prelude::*;
+/// let mut model = Model::new();
+/// let x = model.add_variable().non_negative().continuous();
+/// let y = model.add_variable().non_negative().continuous();
+///
+/// lp!(model, maximize 3*x + 2*y);
+/// let cap = lp!(model, 3*x + 2*y <= 10, "cap");
+/// lp!(model, x - y >= -2);
+///
+/// assert_eq!(model.constraints()[cap].name(), "cap");
+/// ```
+#[macro_export]
+macro_rules! lp {
+    ($model:expr, maximize $($expr:tt)+) => {
+        $model.set_objective($crate::ObjectiveSense::Maximize, $crate::__lp_expr!($($expr)+))
+    };
+    ($model:expr, minimize $($expr:tt)+) => {
+        $model.set_objective($crate::ObjectiveSense::Minimize, $crate::__lp_expr!($($expr)+))
+    };
+    ($model:expr, $($constraint:tt)+) => {
+        $crate::constraint!($model, $($constraint)+)
+    };
+}
+
+/// Adds a constraint `lhs <= rhs`, `lhs >= rhs` or `lhs == rhs` to a model, optionally
+/// followed by a name, and returns its `ConstraintKey`.
+///
+/// ```
+/// # use rustplex::prelude::*;
+/// let mut model = Model::new();
+/// let x = model.add_variable_array(4).non_negative().continuous();
+/// let c = [1.0, 2.0, 3.0, 4.0];
+///
+/// constraint!(model, sum(i in 0..4) c[i]*x[i] >= 10, "demand");
+/// ```
+#[macro_export]
+macro_rules! constraint {
+    ($model:expr, $($tokens:tt)+) => {
+        $crate::__lp_expr!(@go (@lhs $model;) [] + [] $($tokens)+)
+    };
+}
+
+/// Finishes a constraint once `__lp_expr` has read a side and stopped at the comparison
+/// operator, the name or the end.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lp_constraint {
+    (@lhs $model:expr; $lhs:tt <= $($rest:tt)+) => {
+        $crate::__lp_expr!(@go (@rhs $model; $lhs le;) [] + [] $($rest)+)
+    };
+    (@lhs $model:expr; $lhs:tt >= $($rest:tt)+) => {
+        $crate::__lp_expr!(@go (@rhs $model; $lhs ge;) [] + [] $($rest)+)
+    };
+    (@lhs $model:expr; $lhs:tt == $($rest:tt)+) => {
+        $crate::__lp_expr!(@go (@rhs $model; $lhs eq;) [] + [] $($rest)+)
+    };
+    (@lhs $model:expr; $($rest:tt)*) => {
+        compile_error!("expected a constraint of the form `lhs <= rhs`, `lhs >= rhs` or `lhs == rhs`")
+    };
+
+    (@rhs $model:expr; $lhs:tt $sense:ident; $rhs:tt , $name:expr) => {
+        $model.add_constraint($lhs).name($name).$sense($rhs)
+    };
+    (@rhs $model:expr; $lhs:tt $sense:ident; $rhs:tt) => {
+        $model.add_constraint($lhs).$sense($rhs)
+    };
+    (@rhs $model:expr; $($rest:tt)*) => {
+        compile_error!("expected a single comparison, optionally followed by `, name`")
+    };
+}
+
+/// Builds a `LinearExpr<VariableKey>` from terms separated by `+` and `-`.
+///
+/// The tokens are read up to four at a time, so the recursion depth grows with the number
+/// of terms rather than with every token. Inside `constraint!`, reading stops at the
+/// comparison operator or the name and hands the expression to the continuation `$cont`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lp_expr {
+    // Leading signs
+    (@go $cont:tt [$($acc:tt)*] $sign:tt [] + $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont [$($acc)*] $sign [] $($rest)*)
+    };
+    (@go $cont:tt [$($acc:tt)*] + [] - $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont [$($acc)*] - [] $($rest)*)
+    };
+    (@go $cont:tt [$($acc:tt)*] - [] - $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont [$($acc)*] + [] $($rest)*)
+    };
+    // A sign after a term starts the next term
+    (@go $cont:tt [$($acc:tt)*] $sign:tt [$($term:tt)+] + $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont [$($acc)* ($sign [$($term)+])] + [] $($rest)*)
+    };
+    (@go $cont:tt [$($acc:tt)*] $sign:tt [$($term:tt)+] - $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont [$($acc)* ($sign [$($term)+])] - [] $($rest)*)
+    };
+    // A comparison, a name or the end finishes the expression
+    (@go $cont:tt [$($acc:tt)*] $sign:tt [$($term:tt)+] <= $($rest:tt)*) => {
+        $crate::__lp_expr!(@end $cont [$($acc)* ($sign [$($term)+])] <= $($rest)*)
+    };
+    (@go $cont:tt [$($acc:tt)*] $sign:tt [$($term:tt)+] >= $($rest:tt)*) => {
+        $crate::__lp_expr!(@end $cont [$($acc)* ($sign [$($term)+])] >= $($rest)*)
+    };
+    (@go $cont:tt [$($acc:tt)*] $sign:tt [$($term:tt)+] == $($rest:tt)*) => {
+        $crate::__lp_expr!(@end $cont [$($acc)* ($sign [$($term)+])] == $($rest)*)
+    };
+    (@go $cont:tt [$($acc:tt)*] $sign:tt [$($term:tt)+] , $($rest:tt)*) => {
+        $crate::__lp_expr!(@end $cont [$($acc)* ($sign [$($term)+])] , $($rest)*)
+    };
+    (@go $cont:tt [$($acc:tt)*] $sign:tt [$($term:tt)+]) => {
+        $crate::__lp_expr!(@end $cont [$($acc)* ($sign [$($term)+])])
+    };
+    (@go $cont:tt $acc:tt $sign:tt []) => {
+        compile_error!("expected a term")
+    };
+    (@go $cont:tt $acc:tt $sign:tt [] <= $($rest:tt)*) => {
+        compile_error!("expected a term")
+    };
+    (@go $cont:tt $acc:tt $sign:tt [] >= $($rest:tt)*) => {
+        compile_error!("expected a term")
+    };
+    (@go $cont:tt $acc:tt $sign:tt [] == $($rest:tt)*) => {
+        compile_error!("expected a term")
+    };
+    (@go $cont:tt $acc:tt $sign:tt [] , $($rest:tt)*) => {
+        compile_error!("expected a term")
+    };
+    // Otherwise the next tokens up to the first separator belong to the current term
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt + $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a] + $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt - $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a] - $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt <= $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a] <= $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt >= $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a] >= $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt == $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a] == $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt , $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a] , $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt + $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b] + $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt - $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b] - $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt <= $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b] <= $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt >= $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b] >= $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt == $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b] == $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt , $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b] , $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt $c:tt + $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b $c] + $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt $c:tt - $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b $c] - $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt $c:tt <= $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b $c] <= $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt $c:tt >= $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b $c] >= $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt $c:tt == $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b $c] == $($rest)*)
+    };
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt $c:tt , $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b $c] , $($rest)*)
+    };
+    // No separator among the next four tokens
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $a:tt $b:tt $c:tt $d:tt $($rest:tt)*) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $a $b $c $d] $($rest)*)
+    };
+    // Fewer than four tokens left, none of them a separator
+    (@go $cont:tt $acc:tt $sign:tt [$($term:tt)*] $($rest:tt)+) => {
+        $crate::__lp_expr!(@go $cont $acc $sign [$($term)* $($rest)+])
+    };
+
+    (@end () $terms:tt) => {
+        $crate::__lp_expr!(@build $terms)
+    };
+    (@end () $terms:tt $($rest:tt)+) => {
+        compile_error!("expected a linear expression without a comparison")
+    };
+    (@end ($($cont:tt)+) $terms:tt $($rest:tt)*) => {
+        $crate::__lp_constraint!($($cont)+ ($crate::__lp_expr!(@build $terms)) $($rest)*)
+    };
+
+    (@build [$(($signs:tt [$($terms:tt)+]))+]) => {{
+        let mut expr = $crate::LinearExpr::<$crate::VariableKey>::new();
+        $( $crate::__lp_expr!(@apply expr $signs [$($terms)+]); )+
+        expr
+    }};
+
+    (@apply $expr:ident + [$($term:tt)+]) => {
+        $expr += $crate::LinearExpr::<$crate::VariableKey>::from($crate::__lp_term!($($term)+))
+    };
+    (@apply $expr:ident - [$($term:tt)+]) => {
+        $expr -= $crate::LinearExpr::<$crate::VariableKey>::from($crate::__lp_term!($($term)+))
+    };
+
+    ($($tokens:tt)+) => {
+        $crate::__lp_expr!(@go () [] + [] $($tokens)+)
+    };
+}
+
+/// Builds a single term: a sum over an iterator or a product of factors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lp_term {
+    (sum ($index:pat in $iter:expr) $($body:tt)+) => {
+        $crate::quicksum::<$crate::VariableKey, f64, $crate::LinearExpr<$crate::VariableKey>>(
+            ::std::iter::IntoIterator::into_iter($iter).map(|$index| $crate::__lp_expr!($($body)+)),
+        )
+    };
+    ($($tokens:tt)+) => {
+        $crate::__lp_literals!([] $($tokens)+)
+    };
+}
+
+/// Reads the numeric literals of a term as `f64` where they stand for a number: at the
+/// start of the term, inside parentheses or after an arithmetic operator. Indices, call
+/// arguments and tuple fields such as `t.0` are left as is.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lp_literals {
+    ([$($out:tt)*] $($rest:tt)*) => {
+        $crate::__lp_literals!(@operand [$($out)*] $($rest)*)
+    };
+
+    (@$state:ident [$($out:tt)*]) => { $($out)* };
+    // An operator is followed by an operand
+    (@$state:ident [$($out:tt)*] * $($rest:tt)*) => {
+        $crate::__lp_literals!(@operand [$($out)* *] $($rest)*)
+    };
+    (@$state:ident [$($out:tt)*] / $($rest:tt)*) => {
+        $crate::__lp_literals!(@operand [$($out)* /] $($rest)*)
+    };
+    (@$state:ident [$($out:tt)*] + $($rest:tt)*) => {
+        $crate::__lp_literals!(@operand [$($out)* +] $($rest)*)
+    };
+    (@$state:ident [$($out:tt)*] - $($rest:tt)*) => {
+        $crate::__lp_literals!(@operand [$($out)* -] $($rest)*)
+    };
+    (@$state:ident [$($out:tt)*] $function:ident ($($args:tt)*) $($rest:tt)*) => {
+        $crate::__lp_literals!(@other [$($out)* $function($($args)*)] $($rest)*)
+    };
+    (@$state:ident [$($out:tt)*] ($($inner:tt)*) $($rest:tt)*) => {
+        $crate::__lp_literals!(@other [$($out)* ($crate::__lp_literals!([] $($inner)*))] $($rest)*)
+    };
+    (@operand [$($out:tt)*] $literal:literal $($rest:tt)*) => {
+        $crate::__lp_literals!(@other [$($out)* ($literal as f64)] $($rest)*)
+    };
+    (@$state:ident [$($out:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__lp_literals!(@other [$($out)* $token] $($rest)*)
+    };
+}
@@ -1,4 +1,12 @@
 pub mod constraint;
+pub mod indicator;
+mod macros;
 pub mod model;
+pub(crate) mod name_index;
 pub mod objective;
+pub(crate) mod piecewise;
+pub mod solution_check;
+pub mod solution_writer;
+pub mod sos;
 pub mod variable;
+pub mod variable_family;
@@ -1,36 +1,115 @@
+#[cfg(feature = "exact")]
+use crate::solver::exact::{solution::ExactSolution, solver::ExactSimplexSolver};
 use crate::{
-    common::expression::LinearExpr,
+    common::{
+        expression::{quicksum, LinearExpr},
+        scalar::Scalar,
+    },
     error::SolverError,
     modeling::{
-        constraint::{Constraint, ConstraintBuilder, ConstraintKey},
+        constraint::{
+            Constraint, ConstraintBuilder, ConstraintKey, ConstraintSense, TryConstraintBuilder,
+        },
+        indicator::{Indicator, IndicatorBuilder, IndicatorKey},
+        name_index::NameIndex,
         objective::{Objective, ObjectiveSense},
-        variable::{Variable, VariableBuilder, VariableKey, VariableType},
+        piecewise::{self, Auxiliary, Curvature},
+        solution_check::SolutionCheck,
+        solution_writer::SolutionWriter,
+        sos::{SosKey, SosType, SpecialOrderedSet},
+        variable::{
+            SingleVariable, Variable, VariableBuilder, VariableKey, VariableSlot, VariableType,
+            Variables,
+        },
+        variable_family::{Shape, VariableFamilyTarget},
+    },
+    solver::{
+        branch_and_bound::solver::BranchAndBound,
+        callback::{NoCallback, ObjectiveSenseCallback, SolverCallback},
+        cancellation::CancellationToken,
+        config::{SolverAlgorithm, SolverConfig},
+        solution::SolverSolution,
     },
-    solver::{config::SolverConfig, solution::SolverSolution},
     standard_form::standardizer::Standardizer,
 };
-use slotmap::DenseSlotMap;
-use std::fmt;
+use slotmap::{DenseSlotMap, SecondaryMap};
+use std::{
+    fmt,
+    hash::Hash,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+/// Process-unique identity of a `Model`, carried by the variable keys it creates.
+///
+/// Identities fit in 31 bits of a key and repeat after about two billion models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModelId(u32);
+
+impl ModelId {
+    const LIMIT: u32 = 1 << 31;
+
+    fn next() -> Self {
+        static NEXT_ID: AtomicU32 = AtomicU32::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed) % (Self::LIMIT - 1) + 1)
+    }
+
+    /// The identity with number `id`, or `None` for `0`, which no model has.
+    pub(crate) fn new(id: u32) -> Option<Self> {
+        (id != 0 && id < Self::LIMIT).then_some(Self(id))
+    }
+
+    pub(crate) fn get(self) -> u32 {
+        self.0
+    }
+}
 
 #[derive(Debug)]
 pub struct Model {
-    variables: DenseSlotMap<VariableKey, Variable>,
+    id: ModelId,
+    variables: DenseSlotMap<VariableSlot, Variable>,
     constraints: DenseSlotMap<ConstraintKey, Constraint>,
+    sos: DenseSlotMap<SosKey, SpecialOrderedSet>,
+    indicators: DenseSlotMap<IndicatorKey, Indicator>,
+    variable_names: NameIndex<VariableKey>,
+    constraint_names: NameIndex<ConstraintKey>,
+    auxiliaries: Vec<Auxiliary>,
     objective: Option<Objective>,
     config: SolverConfig,
 }
 
+/// A problem found in an expression, before it is tied to a constraint or the objective.
+enum ExpressionIssue {
+    NonFinite {
+        variable: Option<VariableKey>,
+        value: f64,
+    },
+    UnknownVariable(VariableKey),
+}
+
 pub struct ModelDisplay<'a, T> {
     pub model: &'a Model,
     pub item: T,
 }
 
+impl Default for Model {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Model {
     /// Creates a new, empty model with default settings.
     pub fn new() -> Self {
         Self {
+            id: ModelId::next(),
             variables: DenseSlotMap::with_key(),
             constraints: DenseSlotMap::with_key(),
+            sos: DenseSlotMap::with_key(),
+            indicators: DenseSlotMap::with_key(),
+            variable_names: NameIndex::default(),
+            constraint_names: NameIndex::default(),
+            auxiliaries: Vec::new(),
             objective: None,
             config: SolverConfig::default(),
         }
@@ -50,24 +129,450 @@ impl Model {
         self.config.max_iterations = max_iterations;
     }
 
-    /// Sets the numerical tolerance (epsilon) for the solver.
+    /// Sets the primal feasibility, dual feasibility and pivot tolerances of the solver.
     ///
-    /// Default is 1e-10. Values smaller than this are treated as zero.
+    /// Default is 1e-10 for each. Use `with_config` to set them individually.
     pub fn set_tolerance(&mut self, tolerance: f64) {
-        self.config.tolerance = tolerance;
+        #[allow(deprecated)]
+        {
+            self.config.tolerance = tolerance;
+        }
+        self.config.primal_feasibility_tolerance = tolerance;
+        self.config.dual_feasibility_tolerance = tolerance;
+        self.config.pivot_tolerance = tolerance;
+    }
+
+    /// Sets the magnitude at or below which the solver treats coefficients as zero.
+    ///
+    /// Default is 1e-14. Constraints and objectives added afterwards drop such coefficients
+    /// as well.
+    pub fn set_drop_tolerance(&mut self, drop_tolerance: f64) {
+        self.config.drop_tolerance = drop_tolerance;
+    }
+
+    /// Sets the wall-clock budget for the solver.
+    ///
+    /// Default is no limit.
+    pub fn set_time_limit(&mut self, time_limit: Duration) {
+        self.config.time_limit = Some(time_limit);
+    }
+
+    /// Sets the algorithm used to solve the model.
+    ///
+    /// Default is `SolverAlgorithm::Simplex`.
+    pub fn set_algorithm(&mut self, algorithm: SolverAlgorithm) {
+        self.config.algorithm = algorithm;
+    }
+
+    /// Enables or disables the crossover to a basic solution after an interior-point solve.
+    ///
+    /// Default is enabled.
+    pub fn set_crossover(&mut self, crossover: bool) {
+        self.config.crossover = crossover;
+    }
+
+    /// Sets a token that can be used to abort the solve from another thread.
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.config.cancellation_token = Some(token);
     }
 
     // --- Builder Methods ---
 
     pub fn add_variable(&mut self) -> VariableBuilder<'_> {
-        VariableBuilder::new(&mut self.variables)
+        VariableBuilder::new(
+            &mut self.variables,
+            &mut self.variable_names,
+            self.id,
+            SingleVariable,
+        )
+    }
+
+    /// Adds one variable per index, all sharing the bounds and type set on the builder.
+    ///
+    /// Any `Clone + Eq + Hash` value can be an index. `name` labels the variables with
+    /// `VariableIndex` (integers, strings and tuples of them); `name_with` takes a closure
+    /// for any other index, e.g. an enum of your own.
+    ///
+    /// ```
+    /// # use rustplex::prelude::*;
+    /// let mut model = Model::new();
+    /// let plants = ["north", "south"];
+    /// let x = model
+    ///     .add_variables(plants.iter().flat_map(|&p| (0..3).map(move |m| (p, m))))
+    ///     .name("x")
+    ///     .non_negative()
+    ///     .continuous();
+    /// assert_eq!(model.variables()[x[("south", 2)]].name(), "x[south,2]");
+    /// ```
+    pub fn add_variables<I: Clone + Eq + Hash>(
+        &mut self,
+        indices: impl IntoIterator<Item = I>,
+    ) -> VariableBuilder<'_, VariableFamilyTarget<I>> {
+        VariableBuilder::new(
+            &mut self.variables,
+            &mut self.variable_names,
+            self.id,
+            VariableFamilyTarget::new(indices),
+        )
+    }
+
+    /// Adds a dense array of variables of the given shape, e.g. `(200, 300)` for
+    /// variables indexed by `(i, j)` with `i < 200` and `j < 300`.
+    pub fn add_variable_array<S: Shape>(
+        &mut self,
+        shape: S,
+    ) -> VariableBuilder<'_, VariableFamilyTarget<S::Index>> {
+        self.add_variables(shape.indices())
     }
 
     pub fn add_constraint(
         &mut self,
         lhs: impl Into<LinearExpr<VariableKey>>,
     ) -> ConstraintBuilder<'_> {
-        ConstraintBuilder::new(&mut self.constraints, lhs.into())
+        ConstraintBuilder::new(
+            &mut self.constraints,
+            &mut self.constraint_names,
+            lhs.into(),
+            self.config.drop_tolerance,
+        )
+    }
+
+    /// Like `add_constraint`, but finishing the builder reports
+    /// `SolverError::UnknownVariable` instead of adding the constraint when either side
+    /// references a variable of another model, e.g.
+    /// `model.try_add_constraint(x + y).name("cap").le(10.0)?`.
+    pub fn try_add_constraint(
+        &mut self,
+        lhs: impl Into<LinearExpr<VariableKey>>,
+    ) -> TryConstraintBuilder<'_> {
+        TryConstraintBuilder::new(self, lhs.into())
+    }
+
+    /// Adds a special ordered set of type 1: at most one of `vars` may be non-zero, e.g.
+    /// the choice of a single supplier.
+    ///
+    /// `weights` order the variables; branch-and-bound splits the set by weight, so they
+    /// should follow a natural order of the variables (e.g. sizes or positions). Reports
+    /// `SolverError::InvalidSosWeights` unless there is one finite weight per variable and
+    /// all weights are distinct, and `SolverError::DuplicateSosVariable` if a variable is
+    /// listed twice.
+    pub fn add_sos1(
+        &mut self,
+        vars: &[VariableKey],
+        weights: &[f64],
+    ) -> Result<SosKey, SolverError> {
+        self.add_sos(SosType::Sos1, vars, weights)
+    }
+
+    /// Adds a special ordered set of type 2: at most two of `vars` may be non-zero, and
+    /// they must be adjacent in weight order, e.g. the interpolation weights of a
+    /// non-convex piecewise-linear function.
+    ///
+    /// Reports errors like [`Model::add_sos1`].
+    pub fn add_sos2(
+        &mut self,
+        vars: &[VariableKey],
+        weights: &[f64],
+    ) -> Result<SosKey, SolverError> {
+        self.add_sos(SosType::Sos2, vars, weights)
+    }
+
+    fn add_sos(
+        &mut self,
+        sos_type: SosType,
+        vars: &[VariableKey],
+        weights: &[f64],
+    ) -> Result<SosKey, SolverError> {
+        let set = SpecialOrderedSet::new(sos_type, vars, weights)?;
+        Ok(self.sos.insert(set))
+    }
+
+    /// Adds an indicator constraint: `lhs` compared to the right hand side must hold
+    /// whenever the binary variable `binary` equals `active`, e.g.
+    /// `model.add_indicator(open, true, flow).le(capacity)`.
+    ///
+    /// The constraint is modeled with a big-M derived from the bounds of its variables, and
+    /// the search branches on `binary`. Finishing the builder reports
+    /// `SolverError::InvalidIndicatorVariable` if `binary` is not a binary variable of this
+    /// model, and `SolverError::UnboundedIndicator` if a variable needed for the big-M has
+    /// an infinite bound.
+    pub fn add_indicator(
+        &mut self,
+        binary: VariableKey,
+        active: bool,
+        lhs: impl Into<LinearExpr<VariableKey>>,
+    ) -> IndicatorBuilder<'_> {
+        IndicatorBuilder::new(self, binary, active, lhs.into())
+    }
+
+    /// Adds the big-M rows of an indicator constraint whose `rows` read `expr <= 0` while
+    /// it is active.
+    pub(crate) fn insert_indicator(
+        &mut self,
+        binary: VariableKey,
+        active: bool,
+        rows: Vec<(LinearExpr<VariableKey>, Option<String>)>,
+    ) -> Result<IndicatorKey, SolverError> {
+        let is_binary = self.variables().get(binary).map_or(false, |variable| {
+            matches!(variable.var_type(), VariableType::Binary)
+        });
+        if !is_binary {
+            return Err(SolverError::InvalidIndicatorVariable { variable: binary });
+        }
+        let big_ms = rows
+            .iter()
+            .map(|(row, _)| self.upper_estimate(row))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|variable| SolverError::UnboundedIndicator { variable })?;
+
+        let mut constraints = Vec::with_capacity(rows.len());
+        for ((row, name), big_m) in rows.into_iter().zip(big_ms) {
+            // Relaxed to `expr <= M` when the binary takes its other value
+            let big_m = big_m.max(0.0);
+            let (lhs, rhs) = if active {
+                (row + big_m * binary, big_m)
+            } else {
+                (row - big_m * binary, 0.0)
+            };
+            let mut builder = self.add_constraint(lhs);
+            if let Some(name) = name {
+                builder = builder.name(name);
+            }
+            constraints.push(builder.le(rhs));
+        }
+        Ok(self
+            .indicators
+            .insert(Indicator::new(binary, active, constraints)))
+    }
+
+    /// Largest value of `expr` within the variable bounds, or the variable whose infinite
+    /// bound makes it unbounded. Semi-continuous and semi-integer variables may also be
+    /// `0`. Unknown variables are left to `validate`.
+    fn upper_estimate(&self, expr: &LinearExpr<VariableKey>) -> Result<f64, VariableKey> {
+        let mut estimate = expr.constant;
+        for &(var_key, coefficient) in &expr.terms {
+            if let Some(variable) = self.variables().get(var_key) {
+                let (lower, upper) = variable.relaxed_bounds();
+                let bound = if coefficient > 0.0 { upper } else { lower };
+                if !bound.is_finite() {
+                    return Err(var_key);
+                }
+                estimate += coefficient * bound;
+            }
+        }
+        Ok(estimate)
+    }
+
+    /// Smallest and largest value of `expr` within the variable bounds, infinite where a
+    /// bound is.
+    fn range_estimate(&self, expr: &LinearExpr<VariableKey>) -> (f64, f64) {
+        let largest = self.upper_estimate(expr).unwrap_or(f64::INFINITY);
+        let smallest = -self.upper_estimate(&-expr.clone()).unwrap_or(f64::INFINITY);
+        (smallest, largest)
+    }
+
+    /// Adds a piecewise-linear function `y = f(x)` through `breakpoints` `(x, f(x))`, e.g.
+    /// a tiered tariff, and returns the variable `y`. `x` is restricted to the breakpoints'
+    /// range.
+    ///
+    /// `x` and `y` are modeled as a combination of the breakpoints whose weights form an
+    /// SOS2 set, so only two adjacent breakpoints may be combined. The LP relaxation of
+    /// the set is exact when the objective pushes `y` towards `f(x)` and no constraint
+    /// holds it away: a convex `f` (non-decreasing slopes) that is minimized, or a concave
+    /// `f` that is maximized. Any other use, such as a non-convex `f` or a bound on `y`
+    /// from the wrong side, is solved by branch-and-bound on the set.
+    ///
+    /// ```
+    /// # use rustplex::prelude::*;
+    /// let mut model = Model::new();
+    /// let x = model.add_variable().continuous();
+    /// let cost = model
+    ///     .add_piecewise_linear(x, &[(0.0, 0.0), (10.0, 10.0), (20.0, 30.0)])
+    ///     .unwrap();
+    /// model.add_constraint(x).ge(15.0);
+    /// model.set_objective(Minimize, cost);
+    /// let solution = model.solve().unwrap();
+    /// assert!((solution[cost] - 20.0).abs() < 1e-9);
+    /// ```
+    pub fn add_piecewise_linear(
+        &mut self,
+        x: VariableKey,
+        breakpoints: &[(f64, f64)],
+    ) -> Result<VariableKey, SolverError> {
+        let slopes = piecewise::slopes(breakpoints)
+            .ok_or(SolverError::InvalidBreakpoints { variable: x })?;
+        let curvature = piecewise::curvature(&slopes);
+
+        let name = self.variables().get(x).and_then(Variable::explicit_name);
+        let name = name.map(|name| format!("pwl({})", name));
+        let (lowest, highest) = breakpoints.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(lowest, highest), &(_, y_k)| (lowest.min(y_k), highest.max(y_k)),
+        );
+        let mut builder = self.add_variable();
+        if let Some(name) = name {
+            builder = builder.name(name);
+        }
+        let y = builder.bounds(lowest..=highest).continuous();
+
+        // Every point is a convex combination of at most two adjacent breakpoints
+        let lambda: Vec<VariableKey> = breakpoints
+            .iter()
+            .map(|_| self.add_variable().bounds(0.0..=1.0).continuous())
+            .collect();
+        let combine = |value: fn(&(f64, f64)) -> f64| {
+            quicksum(
+                breakpoints
+                    .iter()
+                    .zip(&lambda)
+                    .map(|(point, &l)| value(point) * l),
+            )
+        };
+        let rows = vec![
+            self.add_constraint(quicksum(lambda.iter().copied()))
+                .eq(1.0),
+            self.add_constraint(x).eq(combine(|point| point.0)),
+            self.add_constraint(y).eq(combine(|point| point.1)),
+        ];
+        let weights: Vec<f64> = breakpoints.iter().map(|&(x_k, _)| x_k).collect();
+        let set = SpecialOrderedSet::new(SosType::Sos2, &lambda, &weights)?;
+
+        self.auxiliaries.push(Auxiliary {
+            variable: y,
+            curvature,
+            rows,
+            sets: vec![set],
+        });
+        Ok(y)
+    }
+
+    /// Returns an expression equal to `|expr|`, e.g. `model.abs(a - b)` for a deviation.
+    ///
+    /// Adds a variable `t = p + n` with `expr = p - n`, where `p, n >= 0` form an SOS1
+    /// set. The LP relaxation is exact when the objective pushes `t` down and no constraint
+    /// holds it up, as when minimizing a deviation; other uses, such as
+    /// `abs(x - 3) >= 5`, are solved by branch-and-bound on the set.
+    pub fn abs(&mut self, expr: impl Into<LinearExpr<VariableKey>>) -> LinearExpr<VariableKey> {
+        let expr = expr.into();
+        // Bounded parts keep the relaxation of a bounded `expr` bounded in any direction
+        let (smallest, largest) = self.range_estimate(&expr);
+        let t = self.add_variable().non_negative().continuous();
+        let positive = self
+            .add_variable()
+            .bounds(0.0..=largest.max(0.0))
+            .continuous();
+        let negative = self
+            .add_variable()
+            .bounds(0.0..=(-smallest).max(0.0))
+            .continuous();
+        let rows = vec![
+            self.add_constraint(t).eq(positive + negative),
+            self.add_constraint(expr).eq(positive - negative),
+        ];
+        let set = SpecialOrderedSet::new(SosType::Sos1, &[positive, negative], &[1.0, 2.0])
+            .expect("the parts of abs form a valid set");
+        self.add_auxiliary(t, Curvature::Convex, rows, vec![set])
+    }
+
+    /// Returns an expression equal to the largest of `exprs`, e.g. a makespan.
+    ///
+    /// Adds a variable `t = expr + s` with a slack `s >= 0` for every expression, and
+    /// weights `w >= 0` summing to one, where every slack forms an SOS1 set with its weight
+    /// so that some slack is zero. The LP relaxation is exact when the objective pushes `t`
+    /// down and no constraint holds it up; other uses are solved by branch-and-bound on
+    /// the sets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exprs` is empty.
+    pub fn max_of<E: Into<LinearExpr<VariableKey>>>(
+        &mut self,
+        exprs: impl IntoIterator<Item = E>,
+    ) -> LinearExpr<VariableKey> {
+        self.bound_all(exprs, Curvature::Convex)
+    }
+
+    /// Returns an expression equal to the smallest of `exprs`, e.g. the worst-served
+    /// customer.
+    ///
+    /// Modeled like [`Model::max_of`] with `t = expr - s`. The LP relaxation is exact when
+    /// the objective pushes `t` up and no constraint holds it down; other uses are solved
+    /// by branch-and-bound.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exprs` is empty.
+    pub fn min_of<E: Into<LinearExpr<VariableKey>>>(
+        &mut self,
+        exprs: impl IntoIterator<Item = E>,
+    ) -> LinearExpr<VariableKey> {
+        self.bound_all(exprs, Curvature::Concave)
+    }
+
+    /// Adds a variable bounding every expression from above (convex) or below (concave)
+    /// that equals one of them.
+    fn bound_all<E: Into<LinearExpr<VariableKey>>>(
+        &mut self,
+        exprs: impl IntoIterator<Item = E>,
+        curvature: Curvature,
+    ) -> LinearExpr<VariableKey> {
+        let exprs: Vec<_> = exprs.into_iter().map(Into::into).collect();
+        assert!(
+            !exprs.is_empty(),
+            "max_of and min_of need at least one expression"
+        );
+
+        let ranges: Vec<(f64, f64)> = exprs.iter().map(|expr| self.range_estimate(expr)).collect();
+        let lowest = ranges.iter().fold(f64::INFINITY, |m, r| m.min(r.0));
+        let highest = ranges.iter().fold(f64::NEG_INFINITY, |m, r| m.max(r.1));
+        // Bounded slacks keep the relaxation of bounded expressions bounded in any direction
+        let slack_bounds: Vec<f64> = ranges
+            .iter()
+            .map(|&(smallest, largest)| match curvature {
+                Curvature::Concave => largest - lowest,
+                _ => highest - smallest,
+            })
+            .collect();
+
+        let t = self.add_variable().continuous();
+        let mut rows = Vec::with_capacity(exprs.len() + 1);
+        let mut sets = Vec::with_capacity(exprs.len());
+        let mut weights = Vec::with_capacity(exprs.len());
+        for (expr, slack_bound) in exprs.into_iter().zip(slack_bounds) {
+            let slack = self
+                .add_variable()
+                .bounds(0.0..=slack_bound.max(0.0))
+                .continuous();
+            let weight = self.add_variable().bounds(0.0..=1.0).continuous();
+            let rhs = match curvature {
+                Curvature::Concave => expr - slack,
+                _ => expr + slack,
+            };
+            rows.push(self.add_constraint(t).eq(rhs));
+            // Only an expression with a zero slack may carry weight
+            let set = SpecialOrderedSet::new(SosType::Sos1, &[slack, weight], &[1.0, 2.0])
+                .expect("a slack and its weight form a valid set");
+            sets.push(set);
+            weights.push(weight);
+        }
+        rows.push(self.add_constraint(quicksum(weights)).eq(1.0));
+        self.add_auxiliary(t, curvature, rows, sets)
+    }
+
+    fn add_auxiliary(
+        &mut self,
+        variable: VariableKey,
+        curvature: Curvature,
+        rows: Vec<ConstraintKey>,
+        sets: Vec<SpecialOrderedSet>,
+    ) -> LinearExpr<VariableKey> {
+        self.auxiliaries.push(Auxiliary {
+            variable,
+            curvature: Some(curvature),
+            rows,
+            sets,
+        });
+        variable.into()
     }
 
     pub fn set_objective(
@@ -75,7 +580,36 @@ impl Model {
         sense: ObjectiveSense,
         expression: impl Into<LinearExpr<VariableKey>>,
     ) {
-        self.objective = Some(Objective::new(sense, expression.into()));
+        let mut expression = expression.into();
+        expression.prune(self.config.drop_tolerance);
+        self.objective = Some(Objective::new(sense, expression));
+    }
+
+    /// Like `set_objective`, but reports `SolverError::UnknownObjectiveVariable` and keeps
+    /// the current objective when `expression` references a variable of another model.
+    pub fn try_set_objective(
+        &mut self,
+        sense: ObjectiveSense,
+        expression: impl Into<LinearExpr<VariableKey>>,
+    ) -> Result<(), SolverError> {
+        let expression = expression.into();
+        if let Some(variable) = self.find_unknown_variable(&expression) {
+            return Err(SolverError::UnknownObjectiveVariable { variable });
+        }
+        self.set_objective(sense, expression);
+        Ok(())
+    }
+
+    /// Returns the first variable of `expr` that does not belong to this model.
+    pub(crate) fn find_unknown_variable(
+        &self,
+        expr: &LinearExpr<VariableKey>,
+    ) -> Option<VariableKey> {
+        let variables = self.variables();
+        expr.terms
+            .iter()
+            .map(|&(var_key, _)| var_key)
+            .find(|&var_key| !variables.contains_key(var_key))
     }
 
     /// Returns a helper object that implements Display, allowing you to print
@@ -84,42 +618,368 @@ impl Model {
         ModelDisplay { model: self, item }
     }
 
-    fn is_lp(&self) -> bool {
-        !self
-            .variables
+    /// Returns a writer that exports a solution of this model (`.sol`, CSV or JSON)
+    /// using the variable and constraint names of the model.
+    pub fn solution_writer<'a>(
+        &'a self,
+        solution: &'a SolverSolution<VariableKey>,
+    ) -> SolutionWriter<'a> {
+        SolutionWriter::new(self, solution)
+    }
+
+    /// Checks that the model is well formed before it is solved.
+    ///
+    /// Reports the first problem found: a missing objective or variables, a NaN or
+    /// infinite-in-the-wrong-direction bound, a lower bound above the upper bound (of a
+    /// variable or a range constraint), a non-finite coefficient, or an expression
+    /// referencing a variable that does not belong to this model (including a key created
+    /// by another model).
+    pub fn validate(&self) -> Result<(), SolverError> {
+        if self.variables.is_empty() {
+            return Err(SolverError::NoVariables);
+        }
+        let objective = self
+            .objective
+            .as_ref()
+            .ok_or(SolverError::ObjectiveMissing)?;
+
+        for (var_key, variable) in self.variables() {
+            let (lower, upper) = (variable.lower_bound(), variable.upper_bound());
+            if lower.is_nan() || lower == f64::INFINITY {
+                return Err(SolverError::InvalidBound {
+                    variable: var_key,
+                    value: lower,
+                });
+            } else if upper.is_nan() || upper == f64::NEG_INFINITY {
+                return Err(SolverError::InvalidBound {
+                    variable: var_key,
+                    value: upper,
+                });
+            } else if lower > upper {
+                return Err(SolverError::InvertedBounds {
+                    variable: var_key,
+                    lower,
+                    upper,
+                });
+            }
+        }
+
+        for (constr_key, constraint) in &self.constraints {
+            if let Some((lower, upper)) = constraint.range() {
+                if !lower.is_finite() || !upper.is_finite() || lower > upper {
+                    return Err(SolverError::InvalidRange {
+                        constraint: constr_key,
+                        lower,
+                        upper,
+                    });
+                }
+            }
+            for expr in [constraint.lhs(), constraint.rhs()] {
+                self.validate_expression(expr)
+                    .map_err(|issue| match issue {
+                        ExpressionIssue::NonFinite { variable, value } => {
+                            SolverError::InvalidConstraintCoefficient {
+                                constraint: constr_key,
+                                variable,
+                                value,
+                            }
+                        }
+                        ExpressionIssue::UnknownVariable(variable) => {
+                            SolverError::UnknownConstraintVariable {
+                                constraint: constr_key,
+                                variable,
+                            }
+                        }
+                    })?;
+            }
+        }
+
+        self.validate_expression(objective.expr())
+            .map_err(|issue| match issue {
+                ExpressionIssue::NonFinite { variable, value } => {
+                    SolverError::InvalidObjectiveCoefficient { variable, value }
+                }
+                ExpressionIssue::UnknownVariable(variable) => {
+                    SolverError::UnknownObjectiveVariable { variable }
+                }
+            })?;
+
+        for (set_key, set) in &self.sos {
+            for &(var_key, _) in set.members() {
+                if !self.variables().contains_key(var_key) {
+                    return Err(SolverError::UnknownSosVariable {
+                        set: set_key,
+                        variable: var_key,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the LP relaxation of an auxiliary function is exact, so that its sets need
+    /// no branching.
+    ///
+    /// The relaxation may leave the output above a convex function or below a concave one.
+    /// That is harmless if the objective strictly pushes the output back to the function
+    /// and every other row containing it only gets looser in that direction.
+    fn is_exact(&self, auxiliary: &Auxiliary) -> bool {
+        let curvature = match auxiliary.curvature {
+            Some(Curvature::Linear) => return true,
+            Some(curvature) => curvature,
+            None => return false,
+        };
+        let objective = match &self.objective {
+            Some(objective) => objective,
+            None => return false,
+        };
+        let variable = auxiliary.variable;
+        let sign = match objective.sense() {
+            ObjectiveSense::Maximize => 1.0,
+            ObjectiveSense::Minimize => -1.0,
+        };
+        let rate = sign * objective.expr().coefficient(&variable);
+        if rate == 0.0 || !curvature.is_exact_for(rate) {
+            return false;
+        }
+
+        let in_set = self
+            .sos
             .values()
-            .any(|variable| !matches!(variable.var_type(), VariableType::Continuous))
+            .any(|set| set.members().iter().any(|&(member, _)| member == variable));
+        !in_set
+            && self
+                .constraints
+                .iter()
+                .filter(|(constr_key, _)| !auxiliary.rows.contains(constr_key))
+                .all(|(_, constraint)| {
+                    // `lhs - rhs` is at most, at least or exactly zero
+                    let coefficient = constraint.lhs().coefficient(&variable)
+                        - constraint.rhs().coefficient(&variable);
+                    coefficient == 0.0
+                        || match constraint.sense() {
+                            ConstraintSense::LessEqual => curvature.is_exact_for(-coefficient),
+                            ConstraintSense::GreaterEqual => curvature.is_exact_for(coefficient),
+                            ConstraintSense::Equal | ConstraintSense::Range => false,
+                        }
+                })
+    }
+
+    /// The special ordered sets branch-and-bound must enforce: those of the model and those
+    /// of the auxiliary functions whose LP relaxation is not exact.
+    fn branching_sets(&self) -> Vec<&SpecialOrderedSet> {
+        let auxiliary_sets = self
+            .auxiliaries
+            .iter()
+            .filter(|auxiliary| !self.is_exact(auxiliary))
+            .flat_map(|auxiliary| &auxiliary.sets);
+        self.sos.values().chain(auxiliary_sets).collect()
+    }
+
+    fn validate_expression(&self, expr: &LinearExpr<VariableKey>) -> Result<(), ExpressionIssue> {
+        if !expr.constant.is_finite() {
+            return Err(ExpressionIssue::NonFinite {
+                variable: None,
+                value: expr.constant,
+            });
+        }
+        for &(var_key, coefficient) in &expr.terms {
+            if !self.variables().contains_key(var_key) {
+                return Err(ExpressionIssue::UnknownVariable(var_key));
+            } else if !coefficient.is_finite() {
+                return Err(ExpressionIssue::NonFinite {
+                    variable: Some(var_key),
+                    value: coefficient,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks a solution of this model: primal violations of every constraint and bound,
+    /// integrality violations and, when the solution carries duals, dual infeasibility and
+    /// complementary slackness residuals.
+    pub fn check_solution(&self, solution: &SolverSolution<VariableKey>) -> SolutionCheck {
+        let values = solution.variable_values().clone().unwrap_or_default();
+        SolutionCheck::new(
+            self,
+            &values,
+            solution.dual_values().as_ref(),
+            solution.reduced_costs().as_ref(),
+        )
+    }
+
+    /// Checks an arbitrary assignment, e.g. an externally computed plan, against the
+    /// constraints, bounds and integrality requirements of this model.
+    ///
+    /// Variables missing from `values` are taken as zero.
+    pub fn check_values(&self, values: &SecondaryMap<VariableKey, f64>) -> SolutionCheck {
+        SolutionCheck::new(self, values, None, None)
+    }
+
+    /// Whether every variable is continuous, semi-continuous or semi-integer, or a binary
+    /// the search branches on.
+    fn is_supported(&self) -> bool {
+        self.variables()
+            .iter()
+            .all(|(var_key, variable)| match variable.var_type() {
+                VariableType::Continuous
+                | VariableType::SemiContinuous
+                | VariableType::SemiInteger => true,
+                VariableType::Binary => self
+                    .indicators
+                    .values()
+                    .any(|indicator| indicator.binary() == var_key),
+                VariableType::Integer => false,
+            })
+    }
+
+    /// Whether solving needs branch-and-bound over LP relaxations to enforce `sets`.
+    fn needs_search(&self, sets: &[&SpecialOrderedSet]) -> bool {
+        !sets.is_empty()
+            || !self.indicators.is_empty()
+            || self.variables.values().any(Variable::is_semi)
     }
 
     pub fn solve(&mut self) -> Result<SolverSolution<VariableKey>, SolverError> {
-        if !self.is_lp() {
+        self.solve_with_callback(&mut NoCallback)
+    }
+
+    /// Solves the model, reporting progress to `callback`.
+    ///
+    /// The callback can stop the solve early by returning `CallbackAction::Terminate`,
+    /// in which case the solution status is `SolverStatus::Interrupted`.
+    pub fn solve_with_callback(
+        &mut self,
+        callback: &mut dyn SolverCallback,
+    ) -> Result<SolverSolution<VariableKey>, SolverError> {
+        self.solve_in::<f64>(callback)
+    }
+
+    /// Solves the model with the simplex computing in the scalar type `S`,
+    /// e.g. `model.solve_with_precision::<f32>()`.
+    ///
+    /// Model data is converted to `S` before solving and the results are reported as `f64`.
+    /// A lower precision type is faster and smaller, at the cost of accuracy: the solver
+    /// tolerances are clamped to at least `S::MIN_TOLERANCE`.
+    pub fn solve_with_precision<S: Scalar>(
+        &mut self,
+    ) -> Result<SolverSolution<VariableKey>, SolverError> {
+        self.solve_in::<S>(&mut NoCallback)
+    }
+
+    fn solve_in<S: Scalar>(
+        &mut self,
+        callback: &mut dyn SolverCallback,
+    ) -> Result<SolverSolution<VariableKey>, SolverError> {
+        if !self.is_supported() {
             return Err(SolverError::NonLinearNotSupported);
-        } else if self.variables.is_empty() {
-            return Err(SolverError::NoVariables);
-        } else if self.objective.is_none() {
-            return Err(SolverError::ObjectiveMissing);
         }
+        self.validate()?;
 
         // 1. Compile the domain model into a standard model
-        let (standardizer, mut standardized_model) = Standardizer::compile(&self);
+        let (standardizer, mut standardized_model) = Standardizer::compile(self);
 
         // 2. Solve the math
-        let std_solution = standardized_model.solve()?;
+        let objective_sign = match self.objective.as_ref().unwrap().sense() {
+            ObjectiveSense::Maximize => 1.0,
+            ObjectiveSense::Minimize => -1.0,
+        };
+        let mut callback = ObjectiveSenseCallback::new(callback, objective_sign);
+        let sets = self.branching_sets();
+        let solution = if !self.needs_search(&sets) {
+            let std_solution = standardized_model.solve_with_callback::<S>(&mut callback)?;
 
-        // 3. Lift the result back to the domain
-        let solution = standardizer.reconstruct_solution(&std_solution, &self);
+            // 3. Lift the result back to the domain
+            standardizer.reconstruct_solution(&std_solution, self)
+        } else {
+            // Special ordered sets, indicators and semi-continuous variables are enforced by
+            // branching on LP relaxations
+            BranchAndBound::new(self, &standardizer, standardized_model, sets)
+                .start::<S>(&mut callback)?
+        };
+        let solution = solution.with_variable_names(self.variable_names.clone());
 
         Ok(solution)
     }
 
-    pub fn variables(&self) -> &DenseSlotMap<VariableKey, Variable> {
-        &self.variables
+    /// Solves the model exactly in arbitrary-precision rational arithmetic.
+    ///
+    /// Every coefficient, bound and right hand side is read as its shortest decimal
+    /// representation (e.g. `0.1` as `1/10`). The solve uses Bland's rule on a dense
+    /// tableau, so it is much slower than [`Model::solve`] and meant for small models whose
+    /// optimum must be certified.
+    #[cfg(feature = "exact")]
+    pub fn solve_exact(&self) -> Result<ExactSolution, SolverError> {
+        if !self.is_supported() || self.needs_search(&self.branching_sets()) {
+            return Err(SolverError::NonLinearNotSupported);
+        }
+        self.validate()?;
+
+        Ok(ExactSimplexSolver::from_model(self).start())
+    }
+
+    /// Returns the identity of this model, which its variable keys carry.
+    pub fn id(&self) -> ModelId {
+        self.id
+    }
+
+    /// Returns the variables of the model, keyed by keys that carry the model's identity.
+    pub fn variables(&self) -> Variables<'_> {
+        Variables::new(&self.variables, self.id)
     }
 
     pub fn constraints(&self) -> &DenseSlotMap<ConstraintKey, Constraint> {
         &self.constraints
     }
 
+    /// Returns the special ordered sets of the model.
+    pub fn sos_constraints(&self) -> &DenseSlotMap<SosKey, SpecialOrderedSet> {
+        &self.sos
+    }
+
+    /// Returns the indicator constraints of the model.
+    pub fn indicator_constraints(&self) -> &DenseSlotMap<IndicatorKey, Indicator> {
+        &self.indicators
+    }
+
+    /// Returns the variable named `name`.
+    ///
+    /// `None` if no variable or more than one variable has that name; use
+    /// [`Model::variables_by_name`] to get all of them.
+    pub fn variable_by_name(&self, name: &str) -> Option<VariableKey> {
+        self.variable_names.get(name)
+    }
+
+    /// Returns every variable named `name`, in creation order.
+    pub fn variables_by_name(&self, name: &str) -> &[VariableKey] {
+        self.variable_names.get_all(name)
+    }
+
+    /// Returns the constraint named `name`.
+    ///
+    /// `None` if no constraint or more than one constraint has that name; use
+    /// [`Model::constraints_by_name`] to get all of them.
+    pub fn constraint_by_name(&self, name: &str) -> Option<ConstraintKey> {
+        self.constraint_names.get(name)
+    }
+
+    /// Returns every constraint named `name`, in creation order.
+    pub fn constraints_by_name(&self, name: &str) -> &[ConstraintKey] {
+        self.constraint_names.get_all(name)
+    }
+
+    /// Returns the names given to more than one variable, sorted.
+    pub fn duplicate_variable_names(&self) -> Vec<&str> {
+        self.variable_names.duplicates()
+    }
+
+    /// Returns the names given to more than one constraint, sorted.
+    pub fn duplicate_constraint_names(&self) -> Vec<&str> {
+        self.constraint_names.duplicates()
+    }
+
     pub fn objective(&self) -> Option<&Objective> {
         self.objective.as_ref()
     }
@@ -221,12 +1081,7 @@ impl<'a> fmt::Display for ModelDisplay<'a, &SolverSolution<VariableKey>> {
         if let Some(vars) = self.item.variable_values() {
             writeln!(f, "Variable Values: [")?;
             for (var_key, value) in vars {
-                writeln!(
-                    f,
-                    "\t{}: {:.2}",
-                    self.model.variables.get(var_key).unwrap(),
-                    value
-                )?;
+                writeln!(f, "\t{}: {:.2}", self.model.variables()[var_key], value)?;
             }
             writeln!(f, "]")?;
         } else {
@@ -0,0 +1,52 @@
+use std::{collections::HashMap, sync::Arc};
+
+/// Maps names to the keys that carry them.
+///
+/// The map is shared, so solutions can keep the index of the model they were solved
+/// from without copying it; the model copies it on write only while such a solution is alive.
+#[derive(Debug, Clone)]
+pub(crate) struct NameIndex<K> {
+    keys: Arc<HashMap<String, Vec<K>>>,
+}
+
+impl<K> Default for NameIndex<K> {
+    fn default() -> Self {
+        Self {
+            keys: Arc::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K: Copy> NameIndex<K> {
+    pub(crate) fn insert(&mut self, name: &str, key: K) {
+        Arc::make_mut(&mut self.keys)
+            .entry(name.to_owned())
+            .or_default()
+            .push(key);
+    }
+
+    /// Returns the key carrying `name`, unless no key or several keys carry it.
+    pub(crate) fn get(&self, name: &str) -> Option<K> {
+        match self.get_all(name) {
+            [key] => Some(*key),
+            _ => None,
+        }
+    }
+
+    /// Returns every key carrying `name`, in creation order.
+    pub(crate) fn get_all(&self, name: &str) -> &[K] {
+        self.keys.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the names carried by more than one key, sorted.
+    pub(crate) fn duplicates(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .keys
+            .iter()
+            .filter(|(_, keys)| keys.len() > 1)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        names.sort_unstable();
+        names
+    }
+}
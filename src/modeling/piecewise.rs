@@ -0,0 +1,86 @@
+use crate::modeling::{constraint::ConstraintKey, sos::SpecialOrderedSet, variable::VariableKey};
+
+/// Shape of a function, which decides to which side its LP relaxation may leave it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Curvature {
+    /// Equal slopes: the relaxation keeps `y = f(x)`.
+    Linear,
+    /// Non-decreasing slopes: the relaxation allows `y >= f(x)`, exact when `y` is pushed
+    /// down.
+    Convex,
+    /// Non-increasing slopes: the relaxation allows `y <= f(x)`, exact when `y` is pushed
+    /// up.
+    Concave,
+}
+
+impl Curvature {
+    /// Whether the LP relaxation stays exact for an objective that changes at `rate` per
+    /// unit of the output, with the objective oriented for maximization.
+    pub(crate) fn is_exact_for(self, rate: f64) -> bool {
+        match self {
+            Curvature::Linear => true,
+            Curvature::Convex => rate <= 0.0,
+            Curvature::Concave => rate >= 0.0,
+        }
+    }
+}
+
+/// The output variable of `add_piecewise_linear`, `abs`, `max_of` or `min_of` with the
+/// rows and sets that model it.
+#[derive(Debug, Clone)]
+pub(crate) struct Auxiliary {
+    pub(crate) variable: VariableKey,
+    /// `None` if the function is neither convex nor concave.
+    pub(crate) curvature: Option<Curvature>,
+    /// The rows that define the output, which do not count as uses of it.
+    pub(crate) rows: Vec<ConstraintKey>,
+    /// Sets that hold at every point of the function. Branch-and-bound enforces them
+    /// unless the uses of the output keep the LP relaxation exact.
+    pub(crate) sets: Vec<SpecialOrderedSet>,
+}
+
+/// Slopes of the segments between consecutive breakpoints.
+///
+/// `None` unless there are at least two breakpoints, all finite, with strictly
+/// increasing `x` values.
+pub(crate) fn slopes(breakpoints: &[(f64, f64)]) -> Option<Vec<f64>> {
+    let finite = breakpoints
+        .iter()
+        .all(|(x, y)| x.is_finite() && y.is_finite());
+    if breakpoints.len() < 2 || !finite {
+        return None;
+    }
+    breakpoints
+        .windows(2)
+        .map(|pair| {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            (x1 > x0).then(|| (y1 - y0) / (x1 - x0))
+        })
+        .collect()
+}
+
+/// Relative difference up to which two slopes are taken as equal.
+const SLOPE_TOLERANCE: f64 = 1e-9;
+
+/// Classifies a function by its slopes. `None` if it is neither convex nor concave.
+pub(crate) fn curvature(slopes: &[f64]) -> Option<Curvature> {
+    let compare = |pair: &[f64]| {
+        let difference = pair[1] - pair[0];
+        let scale = 1.0 + pair[0].abs().max(pair[1].abs());
+        if difference.abs() <= SLOPE_TOLERANCE * scale {
+            0
+        } else if difference > 0.0 {
+            1
+        } else {
+            -1
+        }
+    };
+    let increasing = slopes.windows(2).any(|pair| compare(pair) > 0);
+    let decreasing = slopes.windows(2).any(|pair| compare(pair) < 0);
+    match (increasing, decreasing) {
+        (false, false) => Some(Curvature::Linear),
+        (true, false) => Some(Curvature::Convex),
+        (false, true) => Some(Curvature::Concave),
+        (true, true) => None,
+    }
+}
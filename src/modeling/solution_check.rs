@@ -0,0 +1,387 @@
+use crate::{
+    common::expression::LinearExpr,
+    modeling::{
+        constraint::{ConstraintKey, ConstraintSense},
+        model::Model,
+        objective::ObjectiveSense,
+        sos::SosKey,
+        variable::{VariableKey, VariableType},
+    },
+};
+use slotmap::SecondaryMap;
+use std::fmt;
+
+/// How far a point is from satisfying a constraint or bound.
+///
+/// The relative violation divides the absolute one by `1 + |b|`, where `b` is the
+/// constant right hand side of the constraint (or the violated bound).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Violation {
+    pub absolute: f64,
+    pub relative: f64,
+}
+
+impl Violation {
+    fn new(absolute: f64, scale: f64) -> Self {
+        Self {
+            absolute,
+            relative: absolute / (1.0 + scale.abs()),
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self {
+            absolute: self.absolute.max(other.absolute),
+            relative: self.relative.max(other.relative),
+        }
+    }
+}
+
+/// Dual residuals of a solution, available when it carries duals and reduced costs.
+#[derive(Debug, Clone)]
+struct DualResiduals {
+    constraint_infeasibility: SecondaryMap<ConstraintKey, f64>,
+    variable_infeasibility: SecondaryMap<VariableKey, f64>,
+    constraint_complementarity: SecondaryMap<ConstraintKey, f64>,
+    variable_complementarity: SecondaryMap<VariableKey, f64>,
+}
+
+/// A feasibility and optimality report of a point, created by [`Model::check_solution`]
+/// or [`Model::check_values`].
+///
+/// Every constraint, variable and special ordered set of the model has an entry, so a
+/// report can be used to find the rows, bounds and sets a plan violates. Variables missing from the checked values
+/// are taken as zero.
+#[derive(Debug, Clone)]
+pub struct SolutionCheck {
+    constraint_violations: SecondaryMap<ConstraintKey, Violation>,
+    bound_violations: SecondaryMap<VariableKey, Violation>,
+    integrality_violations: SecondaryMap<VariableKey, f64>,
+    sos_violations: SecondaryMap<SosKey, f64>,
+    dual: Option<DualResiduals>,
+}
+
+impl SolutionCheck {
+    pub(crate) fn new(
+        model: &Model,
+        values: &SecondaryMap<VariableKey, f64>,
+        dual_values: Option<&SecondaryMap<ConstraintKey, f64>>,
+        reduced_costs: Option<&SecondaryMap<VariableKey, f64>>,
+    ) -> Self {
+        let value = |var_key: VariableKey| values.get(var_key).copied().unwrap_or(0.0);
+
+        let mut constraint_violations = SecondaryMap::new();
+        for (constr_key, constraint) in model.constraints() {
+            let difference =
+                activity(constraint.lhs(), &value) - activity(constraint.rhs(), &value);
+            let rhs = constraint.rhs().constant - constraint.lhs().constant;
+            let violation = match constraint.sense() {
+                ConstraintSense::LessEqual => Violation::new(difference.max(0.0), rhs),
+                ConstraintSense::GreaterEqual => Violation::new((-difference).max(0.0), rhs),
+                ConstraintSense::Equal => Violation::new(difference.abs(), rhs),
+                ConstraintSense::Range => {
+                    let width = constraint.range_width();
+                    let above = Violation::new(difference.max(0.0), rhs);
+                    let below = Violation::new((-difference - width).max(0.0), rhs - width);
+                    above.max(below)
+                }
+            };
+            constraint_violations.insert(constr_key, violation);
+        }
+
+        let mut bound_violations = SecondaryMap::new();
+        let mut integrality_violations = SecondaryMap::new();
+        for (var_key, variable) in model.variables() {
+            let x = value(var_key);
+            let below = Violation::new(
+                (variable.lower_bound() - x).max(0.0),
+                variable.lower_bound(),
+            );
+            let above = Violation::new(
+                (x - variable.upper_bound()).max(0.0),
+                variable.upper_bound(),
+            );
+            let mut bound_violation = below.max(above);
+            // A semi-continuous or semi-integer variable may also be 0
+            if variable.is_semi() && x.abs() < bound_violation.absolute {
+                bound_violation = Violation::new(x.abs(), 0.0);
+            }
+            bound_violations.insert(var_key, bound_violation);
+
+            let integrality = match variable.var_type() {
+                VariableType::Continuous | VariableType::SemiContinuous => 0.0,
+                VariableType::Integer | VariableType::Binary | VariableType::SemiInteger => {
+                    (x - x.round()).abs()
+                }
+            };
+            integrality_violations.insert(var_key, integrality);
+        }
+
+        let sos_violations = model
+            .sos_constraints()
+            .iter()
+            .map(|(set_key, set)| (set_key, set.violation(value)))
+            .collect();
+
+        let dual = match (dual_values, reduced_costs, model.objective()) {
+            (Some(dual_values), Some(reduced_costs), Some(objective)) => {
+                let sign = match objective.sense() {
+                    ObjectiveSense::Maximize => 1.0,
+                    ObjectiveSense::Minimize => -1.0,
+                };
+                Some(DualResiduals::new(
+                    model,
+                    sign,
+                    &value,
+                    dual_values,
+                    reduced_costs,
+                ))
+            }
+            _ => None,
+        };
+
+        Self {
+            constraint_violations,
+            bound_violations,
+            integrality_violations,
+            sos_violations,
+            dual,
+        }
+    }
+
+    /// Returns the primal violation of every constraint.
+    pub fn constraint_violations(&self) -> &SecondaryMap<ConstraintKey, Violation> {
+        &self.constraint_violations
+    }
+
+    /// Returns the bound violation of every variable.
+    pub fn bound_violations(&self) -> &SecondaryMap<VariableKey, Violation> {
+        &self.bound_violations
+    }
+
+    /// Returns the distance of every variable to the nearest integer
+    /// (zero for continuous variables).
+    pub fn integrality_violations(&self) -> &SecondaryMap<VariableKey, f64> {
+        &self.integrality_violations
+    }
+
+    /// Returns the total magnitude of the members every special ordered set would have to
+    /// drop to zero to be satisfied.
+    pub fn sos_violations(&self) -> &SecondaryMap<SosKey, f64> {
+        &self.sos_violations
+    }
+
+    /// Returns the violation of a specific constraint.
+    pub fn constraint_violation(&self, constr_key: ConstraintKey) -> Violation {
+        self.constraint_violations
+            .get(constr_key)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the bound violation of a specific variable.
+    pub fn bound_violation(&self, var_key: VariableKey) -> Violation {
+        self.bound_violations
+            .get(var_key)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the violation of a specific special ordered set.
+    pub fn sos_violation(&self, set_key: SosKey) -> f64 {
+        self.sos_violations.get(set_key).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the largest absolute and relative violation over all constraints and bounds.
+    pub fn max_primal_violation(&self) -> Violation {
+        self.constraint_violations
+            .values()
+            .chain(self.bound_violations.values())
+            .fold(Violation::default(), |max, violation| max.max(*violation))
+    }
+
+    /// Returns the largest distance of an integer or binary variable to an integer.
+    pub fn max_integrality_violation(&self) -> f64 {
+        self.integrality_violations
+            .values()
+            .fold(0.0, |max, violation| max.max(*violation))
+    }
+
+    /// Returns the largest violation of a special ordered set.
+    pub fn max_sos_violation(&self) -> f64 {
+        max_value(self.sos_violations.values())
+    }
+
+    /// Returns how much every constraint dual has the wrong sign for an optimal solution.
+    ///
+    /// `None` if the checked solution carries no dual information.
+    pub fn constraint_dual_infeasibilities(&self) -> Option<&SecondaryMap<ConstraintKey, f64>> {
+        self.dual
+            .as_ref()
+            .map(|dual| &dual.constraint_infeasibility)
+    }
+
+    /// Returns how much every reduced cost points in a direction the variable is unbounded in.
+    ///
+    /// `None` if the checked solution carries no dual information.
+    pub fn variable_dual_infeasibilities(&self) -> Option<&SecondaryMap<VariableKey, f64>> {
+        self.dual.as_ref().map(|dual| &dual.variable_infeasibility)
+    }
+
+    /// Returns the complementary slackness residual `|dual| * slack` of every constraint.
+    ///
+    /// `None` if the checked solution carries no dual information.
+    pub fn constraint_complementarity(&self) -> Option<&SecondaryMap<ConstraintKey, f64>> {
+        self.dual
+            .as_ref()
+            .map(|dual| &dual.constraint_complementarity)
+    }
+
+    /// Returns the complementary slackness residual `|reduced cost| * distance to bound`
+    /// of every variable.
+    ///
+    /// `None` if the checked solution carries no dual information.
+    pub fn variable_complementarity(&self) -> Option<&SecondaryMap<VariableKey, f64>> {
+        self.dual
+            .as_ref()
+            .map(|dual| &dual.variable_complementarity)
+    }
+
+    /// Returns the largest dual infeasibility over all constraints and variables.
+    pub fn max_dual_infeasibility(&self) -> Option<f64> {
+        self.dual.as_ref().map(|dual| {
+            max_value(dual.constraint_infeasibility.values())
+                .max(max_value(dual.variable_infeasibility.values()))
+        })
+    }
+
+    /// Returns the largest complementary slackness residual over all constraints and variables.
+    pub fn max_complementarity(&self) -> Option<f64> {
+        self.dual.as_ref().map(|dual| {
+            max_value(dual.constraint_complementarity.values())
+                .max(max_value(dual.variable_complementarity.values()))
+        })
+    }
+
+    /// Whether the point satisfies every constraint, bound, integrality requirement and
+    /// special ordered set within `tolerance` (relative for constraints and bounds).
+    pub fn is_feasible(&self, tolerance: f64) -> bool {
+        self.max_primal_violation().relative <= tolerance
+            && self.max_integrality_violation() <= tolerance
+            && self.max_sos_violation() <= tolerance
+    }
+
+    /// Whether the point is feasible and its duals prove optimality within `tolerance`.
+    ///
+    /// Always false if the checked solution carries no dual information.
+    pub fn is_optimal(&self, tolerance: f64) -> bool {
+        self.is_feasible(tolerance)
+            && self
+                .max_dual_infeasibility()
+                .map_or(false, |v| v <= tolerance)
+            && self.max_complementarity().map_or(false, |v| v <= tolerance)
+    }
+}
+
+impl DualResiduals {
+    /// Computes the residuals for an objective with the given sign (1 to maximize, -1 to
+    /// minimize), with duals and reduced costs as rates of change of the objective.
+    fn new(
+        model: &Model,
+        sign: f64,
+        value: &impl Fn(VariableKey) -> f64,
+        dual_values: &SecondaryMap<ConstraintKey, f64>,
+        reduced_costs: &SecondaryMap<VariableKey, f64>,
+    ) -> Self {
+        let mut constraint_infeasibility = SecondaryMap::new();
+        let mut constraint_complementarity = SecondaryMap::new();
+        for (constr_key, constraint) in model.constraints() {
+            // An improving dual must belong to a binding row of the right direction
+            let dual = sign * dual_values.get(constr_key).copied().unwrap_or(0.0);
+            let difference = activity(constraint.lhs(), value) - activity(constraint.rhs(), value);
+            let (infeasibility, complementarity) = match constraint.sense() {
+                ConstraintSense::LessEqual => {
+                    ((-dual).max(0.0), dual.max(0.0) * (-difference).max(0.0))
+                }
+                ConstraintSense::GreaterEqual => {
+                    (dual.max(0.0), (-dual).max(0.0) * difference.max(0.0))
+                }
+                ConstraintSense::Equal => (0.0, 0.0),
+                // Either bound may be binding, depending on the sign of the dual
+                ConstraintSense::Range => {
+                    let width = constraint.range_width();
+                    (
+                        0.0,
+                        dual.max(0.0) * (-difference).max(0.0)
+                            + (-dual).max(0.0) * (difference + width).max(0.0),
+                    )
+                }
+            };
+            constraint_infeasibility.insert(constr_key, infeasibility);
+            constraint_complementarity.insert(constr_key, complementarity);
+        }
+
+        let mut variable_infeasibility = SecondaryMap::new();
+        let mut variable_complementarity = SecondaryMap::new();
+        for (var_key, variable) in model.variables() {
+            // A positive rate asks to increase the variable, so it must sit at its upper bound
+            let rate = sign * reduced_costs.get(var_key).copied().unwrap_or(0.0);
+            let x = value(var_key);
+            let bound = if rate > 0.0 {
+                variable.upper_bound()
+            } else {
+                variable.lower_bound()
+            };
+            let (infeasibility, complementarity) = if rate == 0.0 {
+                (0.0, 0.0)
+            } else if bound.is_infinite() {
+                (rate.abs(), 0.0)
+            } else {
+                (0.0, rate.abs() * (bound - x).abs())
+            };
+            variable_infeasibility.insert(var_key, infeasibility);
+            variable_complementarity.insert(var_key, complementarity);
+        }
+
+        Self {
+            constraint_infeasibility,
+            variable_infeasibility,
+            constraint_complementarity,
+            variable_complementarity,
+        }
+    }
+}
+
+fn activity(expr: &LinearExpr<VariableKey>, value: &impl Fn(VariableKey) -> f64) -> f64 {
+    expr.evaluate(value)
+}
+
+fn max_value<'a>(values: impl Iterator<Item = &'a f64>) -> f64 {
+    values.fold(0.0, |max, value| max.max(*value))
+}
+
+impl fmt::Display for SolutionCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let primal = self.max_primal_violation();
+        writeln!(
+            f,
+            "Max Primal Violation: {:e} (relative {:e})",
+            primal.absolute, primal.relative
+        )?;
+        writeln!(
+            f,
+            "Max Integrality Violation: {:e}",
+            self.max_integrality_violation()
+        )?;
+        if !self.sos_violations.is_empty() {
+            writeln!(f, "Max SOS Violation: {:e}", self.max_sos_violation())?;
+        }
+        match (self.max_dual_infeasibility(), self.max_complementarity()) {
+            (Some(dual), Some(complementarity)) => {
+                writeln!(f, "Max Dual Infeasibility: {:e}", dual)?;
+                write!(f, "Max Complementarity Residual: {:e}", complementarity)
+            }
+            _ => write!(f, "Dual Information: None"),
+        }
+    }
+}
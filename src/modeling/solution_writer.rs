@@ -0,0 +1,318 @@
+use crate::{
+    modeling::{constraint::ConstraintKey, model::Model, variable::VariableKey},
+    solver::solution::SolverSolution,
+};
+use std::{collections::HashSet, fmt::Write as _, io};
+
+/// The file formats a [`SolverSolution`] can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolutionFormat {
+    /// Plain text `.sol` file: a commented header followed by `name value` lines.
+    ///
+    /// Names that are empty or contain whitespace, quotes, backslashes or start with `#`
+    /// are written in double quotes, with `"` and `\` escaped by a backslash.
+    Sol,
+    /// Comma separated values with one row per variable and constraint.
+    Csv,
+    /// A single JSON object.
+    Json,
+}
+
+impl SolutionFormat {
+    /// Guesses the format from a file extension (`sol`, `csv` or `json`).
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "sol" => Some(Self::Sol),
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Writes a solution using the variable and constraint names of the model it came from.
+///
+/// Values are written with full precision (shortest representation that round-trips).
+/// Unnamed variables and constraints are written as `x<n>` and `c<n>`, where `n` is their
+/// position in the model. A generated name that is already given to another variable or
+/// constraint gets a `_<k>` suffix, e.g. `x3_1`.
+pub struct SolutionWriter<'a> {
+    model: &'a Model,
+    solution: &'a SolverSolution<VariableKey>,
+}
+
+impl<'a> SolutionWriter<'a> {
+    pub(crate) fn new(model: &'a Model, solution: &'a SolverSolution<VariableKey>) -> Self {
+        Self { model, solution }
+    }
+
+    /// Writes the solution in the given format.
+    pub fn write(&self, format: SolutionFormat, writer: impl io::Write) -> io::Result<()> {
+        match format {
+            SolutionFormat::Sol => self.write_sol(writer),
+            SolutionFormat::Csv => self.write_csv(writer),
+            SolutionFormat::Json => self.write_json(writer),
+        }
+    }
+
+    /// Writes the solution as a `.sol` text file.
+    pub fn write_sol(&self, mut writer: impl io::Write) -> io::Result<()> {
+        writeln!(writer, "# Status: {:?}", self.solution.status())?;
+        match self.solution.objective_value() {
+            Some(value) => writeln!(writer, "# Objective value = {}", value)?,
+            None => writeln!(writer, "# Objective value = none")?,
+        }
+        writeln!(writer, "# Iterations = {}", self.solution.iterations())?;
+
+        if self.solution.variable_values().is_some() {
+            writeln!(writer, "# Variable values")?;
+            for (name, var_key) in self.variables() {
+                writeln!(
+                    writer,
+                    "{} {}",
+                    sol_name(&name),
+                    self.solution.value(var_key)
+                )?;
+            }
+        }
+
+        if self.solution.reduced_costs().is_some() {
+            writeln!(writer, "# Reduced costs")?;
+            for (name, var_key) in self.variables() {
+                let reduced_cost = self.solution.reduced_cost(var_key);
+                writeln!(writer, "{} {}", sol_name(&name), reduced_cost)?;
+            }
+        }
+
+        if self.solution.dual_values().is_some() {
+            writeln!(writer, "# Dual values")?;
+            for (name, constr_key) in self.constraints() {
+                writeln!(
+                    writer,
+                    "{} {}",
+                    sol_name(&name),
+                    self.solution.dual(constr_key)
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the solution as CSV with the columns `kind,name,value,marginal`.
+    ///
+    /// The marginal is the reduced cost for variables and the dual value for constraints.
+    pub fn write_csv(&self, mut writer: impl io::Write) -> io::Result<()> {
+        let optional = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+
+        writeln!(writer, "kind,name,value,marginal")?;
+        writeln!(writer, "status,{:?},,", self.solution.status())?;
+        writeln!(
+            writer,
+            "objective,,{},",
+            optional(*self.solution.objective_value())
+        )?;
+
+        for (name, var_key) in self.variables() {
+            let value = self.variable_value(var_key);
+            let reduced_cost = self.reduced_cost(var_key);
+            writeln!(
+                writer,
+                "variable,{},{},{}",
+                csv_escape(&name),
+                optional(value),
+                optional(reduced_cost)
+            )?;
+        }
+
+        for (name, constr_key) in self.constraints() {
+            writeln!(
+                writer,
+                "constraint,{},,{}",
+                csv_escape(&name),
+                optional(self.dual(constr_key))
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes the solution as a JSON object.
+    pub fn write_json(&self, mut writer: impl io::Write) -> io::Result<()> {
+        let mut json = String::new();
+
+        json.push_str("{\n");
+        let _ = writeln!(
+            json,
+            "  \"status\": {},",
+            json_string(&format!("{:?}", self.solution.status()))
+        );
+        let _ = writeln!(
+            json,
+            "  \"objective_value\": {},",
+            json_number(*self.solution.objective_value())
+        );
+        let _ = writeln!(json, "  \"iterations\": {},", self.solution.iterations());
+        let _ = writeln!(
+            json,
+            "  \"solve_time_secs\": {},",
+            self.solution.solve_time().as_secs_f64()
+        );
+
+        let variables = self
+            .variables()
+            .into_iter()
+            .map(|(name, var_key)| {
+                format!(
+                    "    {{\"name\": {}, \"value\": {}, \"reduced_cost\": {}}}",
+                    json_string(&name),
+                    json_number(self.variable_value(var_key)),
+                    json_number(self.reduced_cost(var_key))
+                )
+            })
+            .collect::<Vec<_>>();
+        let _ = writeln!(json, "  \"variables\": {},", json_array(&variables));
+
+        let constraints = self
+            .constraints()
+            .into_iter()
+            .map(|(name, constr_key)| {
+                format!(
+                    "    {{\"name\": {}, \"dual\": {}}}",
+                    json_string(&name),
+                    json_number(self.dual(constr_key))
+                )
+            })
+            .collect::<Vec<_>>();
+        let _ = writeln!(json, "  \"constraints\": {}", json_array(&constraints));
+        json.push_str("}\n");
+
+        writer.write_all(json.as_bytes())
+    }
+
+    // --- Private Helpers ---
+
+    fn variables(&self) -> Vec<(String, VariableKey)> {
+        let variables = self.model.variables().iter();
+        unique_names(variables.map(|(key, var)| (key, var.explicit_name())), "x")
+    }
+
+    fn constraints(&self) -> Vec<(String, ConstraintKey)> {
+        let constraints = self.model.constraints().iter();
+        unique_names(
+            constraints.map(|(key, constr)| (key, constr.explicit_name())),
+            "c",
+        )
+    }
+
+    fn variable_value(&self, var_key: VariableKey) -> Option<f64> {
+        self.solution
+            .variable_values()
+            .as_ref()
+            .and_then(|values| values.get(var_key).copied())
+    }
+
+    fn reduced_cost(&self, var_key: VariableKey) -> Option<f64> {
+        self.solution
+            .reduced_costs()
+            .as_ref()
+            .and_then(|costs| costs.get(var_key).copied())
+    }
+
+    fn dual(&self, constr_key: ConstraintKey) -> Option<f64> {
+        self.solution
+            .dual_values()
+            .as_ref()
+            .and_then(|duals| duals.get(constr_key).copied())
+    }
+}
+
+/// Names the items of a model, generating `<prefix><position>` for unnamed ones and
+/// suffixing a generated name with `_<k>` while it is taken by another item.
+fn unique_names<'n, K>(
+    items: impl Iterator<Item = (K, Option<&'n str>)>,
+    prefix: &str,
+) -> Vec<(String, K)> {
+    let items: Vec<_> = items.collect();
+    let mut taken: HashSet<String> = items
+        .iter()
+        .filter_map(|(_, name)| name.map(String::from))
+        .collect();
+
+    let mut names = Vec::with_capacity(items.len());
+    for (index, (key, name)) in items.into_iter().enumerate() {
+        let name = match name {
+            Some(name) => name.to_string(),
+            None => {
+                let mut generated = format!("{}{}", prefix, index);
+                let mut suffix = 1;
+                while taken.contains(&generated) {
+                    generated = format!("{}{}_{}", prefix, index, suffix);
+                    suffix += 1;
+                }
+                taken.insert(generated.clone());
+                generated
+            }
+        };
+        names.push((name, key));
+    }
+    names
+}
+
+/// Quotes a `.sol` name if it could not be told apart from the value or a comment.
+fn sol_name(name: &str) -> String {
+    let plain = !name.is_empty()
+        && !name.starts_with('#')
+        && !name.contains(|c: char| c.is_whitespace() || c == '"' || c == '\\');
+    if plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, a quote or a line break.
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Formats a string as a JSON string literal.
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Formats already serialized items as a JSON array, one item per line.
+fn json_array(items: &[String]) -> String {
+    if items.is_empty() {
+        String::from("[]")
+    } else {
+        format!("[\n{}\n  ]", items.join(",\n"))
+    }
+}
+
+/// Formats a number as a JSON value. JSON has no representation for NaN or infinity,
+/// so those (and missing values) are written as `null`.
+fn json_number(value: Option<f64>) -> String {
+    match value {
+        Some(v) if v.is_finite() => v.to_string(),
+        _ => String::from("null"),
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{error::SolverError, modeling::variable::VariableKey};
+use slotmap::new_key_type;
+use std::fmt;
+
+new_key_type! {
+    pub struct SosKey;
+}
+
+impl fmt::Display for SosKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SosKey({:?})", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SosType {
+    /// At most one member is non-zero.
+    Sos1,
+    /// At most two members are non-zero, and they are consecutive in weight order.
+    Sos2,
+}
+
+/// A special ordered set: variables ordered by weight, of which only one (SOS1) or two
+/// consecutive ones (SOS2) may be non-zero.
+#[derive(Debug, Clone)]
+pub struct SpecialOrderedSet {
+    sos_type: SosType,
+    members: Vec<(VariableKey, f64)>,
+}
+
+impl SpecialOrderedSet {
+    /// Creates a set from its members, sorted by weight.
+    ///
+    /// Fails unless there is one finite weight per variable, all weights are distinct and
+    /// no variable is repeated.
+    pub(crate) fn new(
+        sos_type: SosType,
+        variables: &[VariableKey],
+        weights: &[f64],
+    ) -> Result<Self, SolverError> {
+        if variables.len() != weights.len() || weights.iter().any(|weight| !weight.is_finite()) {
+            return Err(SolverError::InvalidSosWeights);
+        }
+        let mut sorted = variables.to_vec();
+        sorted.sort();
+        if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(SolverError::DuplicateSosVariable { variable: pair[0] });
+        }
+        let mut members: Vec<_> = variables
+            .iter()
+            .copied()
+            .zip(weights.iter().copied())
+            .collect();
+        members.sort_by(|a, b| a.1.total_cmp(&b.1));
+        if members.windows(2).any(|pair| pair[0].1 == pair[1].1) {
+            return Err(SolverError::InvalidSosWeights);
+        }
+        Ok(Self { sos_type, members })
+    }
+
+    /// Returns whether this is an SOS1 or an SOS2 set.
+    pub fn sos_type(&self) -> SosType {
+        self.sos_type
+    }
+
+    /// Returns the variables of the set with their weights, in increasing weight order.
+    pub fn members(&self) -> &[(VariableKey, f64)] {
+        &self.members
+    }
+
+    /// Returns how far a point is from satisfying the set: the total magnitude of the
+    /// members outside the one (SOS1) or two adjacent (SOS2) members that may stay non-zero.
+    pub(crate) fn violation(&self, value: impl Fn(VariableKey) -> f64) -> f64 {
+        let magnitudes: Vec<f64> = self
+            .members
+            .iter()
+            .map(|&(var, _)| value(var).abs())
+            .collect();
+        let span = match self.sos_type {
+            SosType::Sos1 => 1,
+            SosType::Sos2 => 2,
+        };
+        let kept = magnitudes
+            .windows(span.min(magnitudes.len()).max(1))
+            .map(|window| window.iter().sum::<f64>())
+            .fold(0.0, f64::max);
+        (magnitudes.iter().sum::<f64>() - kept).max(0.0)
+    }
+
+    /// Splits the set for branch-and-bound if the point violates it.
+    ///
+    /// Returns the variables to fix at zero in each of both branches. The split follows
+    /// the weighted average of the non-zero members, and each branch excludes the point.
+    pub(crate) fn branch(
+        &self,
+        value: impl Fn(VariableKey) -> f64,
+        tolerance: f64,
+    ) -> Option<(Vec<VariableKey>, Vec<VariableKey>)> {
+        let magnitudes: Vec<f64> = self
+            .members
+            .iter()
+            .map(|&(var, _)| value(var).abs())
+            .collect();
+        let first = magnitudes.iter().position(|&m| m > tolerance)?;
+        let last = magnitudes.iter().rposition(|&m| m > tolerance)?;
+
+        let span = match self.sos_type {
+            SosType::Sos1 => 0,
+            SosType::Sos2 => 1,
+        };
+        if last - first <= span {
+            return None;
+        }
+
+        let total: f64 = magnitudes.iter().sum();
+        let average = self
+            .members
+            .iter()
+            .zip(&magnitudes)
+            .map(|(&(_, weight), &m)| weight * m)
+            .sum::<f64>()
+            / total;
+        let split = self
+            .members
+            .iter()
+            .rposition(|&(_, weight)| weight <= average)
+            .unwrap_or(0);
+
+        // The left branch keeps members up to `split`, the right one from the next member
+        // on (SOS1) or from `split` itself (SOS2), so both of them cut off `first` or `last`
+        let keys = |range: std::ops::Range<usize>| {
+            self.members[range]
+                .iter()
+                .map(|&(var, _)| var)
+                .collect::<Vec<_>>()
+        };
+        let n = self.members.len();
+        match self.sos_type {
+            SosType::Sos1 => {
+                let split = split.clamp(first, last - 1);
+                Some((keys(split + 1..n), keys(0..split + 1)))
+            }
+            SosType::Sos2 => {
+                let split = split.clamp(first + 1, last - 1);
+                Some((keys(split + 1..n), keys(0..split)))
+            }
+        }
+    }
+}
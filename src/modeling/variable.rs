@@ -1,16 +1,60 @@
-use crate::common::expression::{impl_expr_display, impl_expr_ops, ExprVariable};
-use slotmap::{new_key_type, DenseSlotMap};
+use crate::{
+    common::expression::{impl_expr_display, impl_expr_ops, ExprVariable},
+    modeling::{
+        model::ModelId,
+        name_index::NameIndex,
+        variable_family::{VariableFamilyTarget, VariableIndex},
+    },
+};
+use slotmap::{dense, new_key_type, DenseSlotMap, Key, KeyData};
 use std::fmt;
-use std::ops::RangeInclusive;
+use std::hash::Hash;
+use std::ops::{Index, RangeInclusive};
 
 new_key_type! {
+    /// Slot of a variable in the arena of its model.
+    pub(crate) struct VariableSlot;
+}
+
+new_key_type! {
+    /// Key of a variable in a `Model`.
+    ///
+    /// The key data carries the model that created the key, so every copy of a key keeps
+    /// it, including keys rebuilt by a `SecondaryMap` or a solution. Keys of different
+    /// models never compare equal, and a model rejects keys it did not create.
     pub struct VariableKey;
 }
 
+impl VariableKey {
+    /// Variables are never removed from a model, so every slot keeps its first version,
+    /// `1`. The key stores the model in the remaining version bits instead.
+    const SLOT_VERSION: u64 = 1 << 32;
+
+    pub(crate) fn new(slot: VariableSlot, model: ModelId) -> Self {
+        let slot = slot.data().as_ffi();
+        debug_assert_eq!(slot >> 32, 1, "variables are never removed");
+        let model = u64::from(model.get()) << 33;
+        Self(KeyData::from_ffi(
+            model | Self::SLOT_VERSION | (slot & 0xffff_ffff),
+        ))
+    }
+
+    /// Returns the model that created this key, or `None` for a key made up from raw slot
+    /// data.
+    pub fn model(&self) -> Option<ModelId> {
+        ModelId::new((self.0.as_ffi() >> 33) as u32)
+    }
+
+    /// Returns the slot of the variable in the arena of its model.
+    pub(crate) fn slot(&self) -> VariableSlot {
+        KeyData::from_ffi(Self::SLOT_VERSION | (self.0.as_ffi() & 0xffff_ffff)).into()
+    }
+}
+
 impl fmt::Display for VariableKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Displays the internal ID (e.g., "Var(1v1)")
-        write!(f, "VariableKey({:?})", self.0)
+        write!(f, "VariableKey({:?})", self.slot().data())
     }
 }
 
@@ -24,9 +68,13 @@ pub enum VariableType {
     Continuous,
     Integer,
     Binary,
+    /// Either `0` or within the bounds, e.g. a production run with a minimum lot size.
+    SemiContinuous,
+    /// Either `0` or an integer within the bounds.
+    SemiInteger,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Variable {
     name: Option<String>,
     var_type: VariableType,
@@ -41,6 +89,15 @@ impl Variable {
         self.name.as_deref().unwrap_or("<unnamed>")
     }
 
+    /// Returns the name of the variable, if one was set.
+    pub(crate) fn explicit_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub(crate) fn set_name(&mut self, name: Option<String>) {
+        self.name = name;
+    }
+
     /// Returns the type of the variable.
     pub fn var_type(&self) -> VariableType {
         self.var_type
@@ -55,6 +112,24 @@ impl Variable {
     pub fn upper_bound(&self) -> f64 {
         self.upper_bound
     }
+
+    /// Whether the variable is semi-continuous or semi-integer.
+    pub fn is_semi(&self) -> bool {
+        matches!(
+            self.var_type,
+            VariableType::SemiContinuous | VariableType::SemiInteger
+        )
+    }
+
+    /// Returns the bounds of the variable, widened to include `0` for a semi-continuous or
+    /// semi-integer variable.
+    pub(crate) fn relaxed_bounds(&self) -> (f64, f64) {
+        if self.is_semi() {
+            (self.lower_bound.min(0.0), self.upper_bound.max(0.0))
+        } else {
+            (self.lower_bound, self.upper_bound)
+        }
+    }
 }
 
 impl Default for Variable {
@@ -74,6 +149,8 @@ impl fmt::Display for Variable {
             VariableType::Continuous => "cont",
             VariableType::Integer => "int",
             VariableType::Binary => "bin",
+            VariableType::SemiContinuous => "semicont",
+            VariableType::SemiInteger => "semiint",
         };
 
         write!(
@@ -87,27 +164,188 @@ impl fmt::Display for Variable {
     }
 }
 
-// --- Variable Builder ---
+// --- Model Variables ---
 
-/// A builder for creating and configuring a new variable.
-pub struct VariableBuilder<'a> {
-    arena: &'a mut DenseSlotMap<VariableKey, Variable>,
-    data: Variable,
+/// The variables of a model, as returned by `Model::variables`.
+///
+/// Works like a read-only slotmap, except that the keys it yields carry the model's
+/// identity. Keys of another model are not found.
+#[derive(Debug, Clone, Copy)]
+pub struct Variables<'a> {
+    arena: &'a DenseSlotMap<VariableSlot, Variable>,
+    model: ModelId,
 }
 
-impl<'a> VariableBuilder<'a> {
-    pub(crate) fn new(arena: &'a mut DenseSlotMap<VariableKey, Variable>) -> Self {
-        Self {
-            arena,
-            data: Variable::default(),
+impl<'a> Variables<'a> {
+    pub(crate) fn new(arena: &'a DenseSlotMap<VariableSlot, Variable>, model: ModelId) -> Self {
+        Self { arena, model }
+    }
+
+    /// Returns the number of variables.
+    pub fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    /// Returns `true` if the model has no variables.
+    pub fn is_empty(&self) -> bool {
+        self.arena.is_empty()
+    }
+
+    /// Returns `true` if `key` is a variable of this model.
+    pub fn contains_key(&self, key: VariableKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the variable of `key`, or `None` if it is not a variable of this model.
+    pub fn get(&self, key: VariableKey) -> Option<&'a Variable> {
+        if key.model() != Some(self.model) {
+            return None;
         }
+        self.arena.get(key.slot())
     }
 
+    /// Iterates over the keys and variables, in insertion order unless variables were
+    /// removed.
+    pub fn iter(&self) -> VariablesIter<'a> {
+        VariablesIter {
+            inner: self.arena.iter(),
+            model: self.model,
+        }
+    }
+
+    /// Iterates over the keys.
+    pub fn keys(&self) -> impl Iterator<Item = VariableKey> + 'a {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Iterates over the variables.
+    pub fn values(&self) -> impl Iterator<Item = &'a Variable> + 'a {
+        self.arena.values()
+    }
+}
+
+impl<'a> Index<VariableKey> for Variables<'a> {
+    type Output = Variable;
+
+    fn index(&self, key: VariableKey) -> &Variable {
+        match self.get(key) {
+            Some(variable) => variable,
+            None => panic!("invalid VariableKey used"),
+        }
+    }
+}
+
+impl<'a> IntoIterator for Variables<'a> {
+    type Item = (VariableKey, &'a Variable);
+    type IntoIter = VariablesIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the keys and variables of a model.
+#[derive(Debug, Clone)]
+pub struct VariablesIter<'a> {
+    inner: dense::Iter<'a, VariableSlot, Variable>,
+    model: ModelId,
+}
+
+impl<'a> Iterator for VariablesIter<'a> {
+    type Item = (VariableKey, &'a Variable);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(slot, variable)| (VariableKey::new(slot, self.model), variable))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+// --- Variable Builder ---
+
+/// What a [`VariableBuilder`] creates once it is finalized: a single variable
+/// ([`SingleVariable`]) or one variable per index ([`VariableFamilyTarget`]).
+pub trait VariableTarget {
+    type Output;
+
+    /// Creates the output, adding each variable to the model through `insert`.
+    #[doc(hidden)]
+    fn create(
+        self,
+        insert: &mut dyn FnMut(Variable) -> VariableKey,
+        data: Variable,
+    ) -> Self::Output;
+}
+
+/// Target of [`Model::add_variable`](crate::Model::add_variable): one variable.
+pub struct SingleVariable;
+
+impl VariableTarget for SingleVariable {
+    type Output = VariableKey;
+
+    fn create(
+        self,
+        insert: &mut dyn FnMut(Variable) -> VariableKey,
+        data: Variable,
+    ) -> VariableKey {
+        insert(data)
+    }
+}
+
+/// A builder for creating and configuring a new variable, or a family of variables
+/// that share their bounds and type.
+pub struct VariableBuilder<'a, T: VariableTarget = SingleVariable> {
+    arena: &'a mut DenseSlotMap<VariableSlot, Variable>,
+    names: &'a mut NameIndex<VariableKey>,
+    model: ModelId,
+    target: T,
+    data: Variable,
+}
+
+impl<'a> VariableBuilder<'a, SingleVariable> {
     /// Sets the name of the variable.
     pub fn name(mut self, name: impl Into<String>) -> Self {
         self.data.name = Some(name.into());
         self
     }
+}
+
+impl<'a, I: VariableIndex> VariableBuilder<'a, VariableFamilyTarget<I>> {
+    /// Names the variables of the family `name[index]`, e.g. `x[3,7]`.
+    pub fn name(self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.name_with(|index| format!("{}[{}]", name, index.label()))
+    }
+}
+
+impl<'a, I: Clone + Eq + Hash> VariableBuilder<'a, VariableFamilyTarget<I>> {
+    /// Names the variable of each index with `name`, e.g.
+    /// `.name_with(|plant: &Plant| format!("open_{:?}", plant))` for an enum index.
+    pub fn name_with(mut self, name: impl FnMut(&I) -> String) -> Self {
+        self.target.set_names(name);
+        self
+    }
+}
+
+impl<'a, T: VariableTarget> VariableBuilder<'a, T> {
+    pub(crate) fn new(
+        arena: &'a mut DenseSlotMap<VariableSlot, Variable>,
+        names: &'a mut NameIndex<VariableKey>,
+        model: ModelId,
+        target: T,
+    ) -> Self {
+        Self {
+            arena,
+            names,
+            model,
+            target,
+            data: Variable::default(),
+        }
+    }
 
     /// Sets the lower bound of the variable.
     pub fn lower_bound(mut self, lb: f64) -> Self {
@@ -145,31 +383,51 @@ impl<'a> VariableBuilder<'a> {
     // --- Terminating Methods ---
 
     /// Finalizes the variable as **Continuous**.
-    pub fn continuous(self) -> VariableKey {
+    pub fn continuous(self) -> T::Output {
         self.finish(VariableType::Continuous)
     }
 
     /// Alias for `continuous()`.
-    pub fn real(self) -> VariableKey {
+    pub fn real(self) -> T::Output {
         self.continuous()
     }
 
     /// Finalizes the variable as **Integer**.
-    pub fn integer(self) -> VariableKey {
+    pub fn integer(self) -> T::Output {
         self.finish(VariableType::Integer)
     }
 
     /// Finalizes the variable as **Binary**.
     ///
     /// This automatically sets the bounds to [0.0, 1.0].
-    pub fn binary(mut self) -> VariableKey {
+    pub fn binary(mut self) -> T::Output {
         self.data.lower_bound = 0.0;
         self.data.upper_bound = 1.0;
         self.finish(VariableType::Binary)
     }
 
-    fn finish(mut self, var_type: VariableType) -> VariableKey {
+    /// Finalizes the variable as **Semi-continuous**: either `0` or within its bounds.
+    pub fn semi_continuous(self) -> T::Output {
+        self.finish(VariableType::SemiContinuous)
+    }
+
+    /// Finalizes the variable as **Semi-integer**: either `0` or an integer within its
+    /// bounds.
+    pub fn semi_integer(self) -> T::Output {
+        self.finish(VariableType::SemiInteger)
+    }
+
+    fn finish(mut self, var_type: VariableType) -> T::Output {
         self.data.var_type = var_type;
-        self.arena.insert(self.data)
+        let (arena, names, model) = (self.arena, self.names, self.model);
+        let mut insert = |variable: Variable| {
+            let slot = arena.insert(variable);
+            let var_key = VariableKey::new(slot, model);
+            if let Some(name) = arena[slot].explicit_name() {
+                names.insert(name, var_key);
+            }
+            var_key
+        };
+        self.target.create(&mut insert, self.data)
     }
 }
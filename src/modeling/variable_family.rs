@@ -0,0 +1,203 @@
+use crate::modeling::variable::{Variable, VariableKey, VariableTarget};
+use std::{collections::HashMap, hash::Hash, ops::Index};
+
+/// An index with a label, used by `VariableBuilder::name` to name the variables of a
+/// family, e.g. `x[3,7]`.
+///
+/// Implemented for integers, `char`, strings and tuples of up to four indices. Any other
+/// `Clone + Eq + Hash` value, such as your own enum, can index a family too; name its
+/// variables with `VariableBuilder::name_with`.
+pub trait VariableIndex: Clone + Eq + Hash {
+    fn label(&self) -> String;
+}
+
+macro_rules! impl_variable_index_display {
+    ($($type:ty),*) => {
+        $(
+            impl VariableIndex for $type {
+                fn label(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_variable_index_display!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, bool, String
+);
+
+impl VariableIndex for &str {
+    fn label(&self) -> String {
+        self.to_string()
+    }
+}
+
+macro_rules! impl_variable_index_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: VariableIndex),+> VariableIndex for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn label(&self) -> String {
+                let ($($name,)+) = self;
+                [$($name.label()),+].join(",")
+            }
+        }
+    };
+}
+
+impl_variable_index_tuple!(A, B);
+impl_variable_index_tuple!(A, B, C);
+impl_variable_index_tuple!(A, B, C, D);
+
+/// The dimensions of a dense variable array, see
+/// [`Model::add_variable_array`](crate::Model::add_variable_array).
+///
+/// `n` gives indices `0..n`, `(n, m)` gives `(i, j)` and `(n, m, k)` gives `(i, j, l)`
+/// in row-major order.
+pub trait Shape {
+    type Index: VariableIndex;
+
+    fn indices(self) -> Vec<Self::Index>;
+}
+
+impl Shape for usize {
+    type Index = usize;
+
+    fn indices(self) -> Vec<usize> {
+        (0..self).collect()
+    }
+}
+
+impl Shape for (usize, usize) {
+    type Index = (usize, usize);
+
+    fn indices(self) -> Vec<(usize, usize)> {
+        let (rows, columns) = self;
+        (0..rows)
+            .flat_map(|i| (0..columns).map(move |j| (i, j)))
+            .collect()
+    }
+}
+
+impl Shape for (usize, usize, usize) {
+    type Index = (usize, usize, usize);
+
+    fn indices(self) -> Vec<(usize, usize, usize)> {
+        let (rows, columns, depth) = self;
+        (0..rows)
+            .flat_map(|i| (0..columns).flat_map(move |j| (0..depth).map(move |k| (i, j, k))))
+            .collect()
+    }
+}
+
+/// Target of [`Model::add_variables`](crate::Model::add_variables): one variable per index.
+pub struct VariableFamilyTarget<I: Clone + Eq + Hash> {
+    indices: Vec<I>,
+    names: Option<Vec<String>>,
+}
+
+impl<I: Clone + Eq + Hash> VariableFamilyTarget<I> {
+    pub(crate) fn new(indices: impl IntoIterator<Item = I>) -> Self {
+        Self {
+            indices: indices.into_iter().collect(),
+            names: None,
+        }
+    }
+
+    /// Names the variable of every index with `name`.
+    pub(crate) fn set_names(&mut self, name: impl FnMut(&I) -> String) {
+        self.names = Some(self.indices.iter().map(name).collect());
+    }
+}
+
+impl<I: Clone + Eq + Hash> VariableTarget for VariableFamilyTarget<I> {
+    type Output = VariableFamily<I>;
+
+    fn create(
+        self,
+        insert: &mut dyn FnMut(Variable) -> VariableKey,
+        data: Variable,
+    ) -> VariableFamily<I> {
+        let mut family = VariableFamily {
+            indices: Vec::with_capacity(self.indices.len()),
+            keys: Vec::with_capacity(self.indices.len()),
+            positions: HashMap::with_capacity(self.indices.len()),
+        };
+
+        let mut names = self.names.map(Vec::into_iter);
+        for index in self.indices {
+            let name = names.as_mut().and_then(Iterator::next);
+            if family.positions.contains_key(&index) {
+                continue;
+            }
+            let mut variable = data.clone();
+            variable.set_name(name);
+
+            family.positions.insert(index.clone(), family.keys.len());
+            family.keys.push(insert(variable));
+            family.indices.push(index);
+        }
+        family
+    }
+}
+
+/// Variables created together, one per index, e.g. `x[(i, j)]` of a transportation model.
+///
+/// Iteration follows the order of the indices given at creation. Repeated indices
+/// create a single variable.
+#[derive(Debug, Clone)]
+pub struct VariableFamily<I: Clone + Eq + Hash> {
+    indices: Vec<I>,
+    keys: Vec<VariableKey>,
+    positions: HashMap<I, usize>,
+}
+
+impl<I: Clone + Eq + Hash> VariableFamily<I> {
+    /// Returns the variable of an index, if the family has one.
+    pub fn get(&self, index: &I) -> Option<VariableKey> {
+        self.positions
+            .get(index)
+            .map(|&position| self.keys[position])
+    }
+
+    /// Returns the number of variables in the family.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the family has no variables.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the indices of the family in creation order.
+    pub fn indices(&self) -> &[I] {
+        &self.indices
+    }
+
+    /// Returns the variables of the family in creation order.
+    pub fn keys(&self) -> &[VariableKey] {
+        &self.keys
+    }
+
+    /// Iterates over `(index, variable)` pairs in creation order.
+    pub fn iter(&self) -> impl Iterator<Item = (&I, VariableKey)> + '_ {
+        self.indices.iter().zip(self.keys.iter().copied())
+    }
+}
+
+/// Allows indexing notation `x[(i, j)]` to retrieve the variable of an index.
+///
+/// # Panics
+///
+/// Panics if the family has no variable for the index.
+impl<I: Clone + Eq + Hash> Index<I> for VariableFamily<I> {
+    type Output = VariableKey;
+
+    fn index(&self, index: I) -> &VariableKey {
+        match self.positions.get(&index) {
+            Some(&position) => &self.keys[position],
+            None => panic!("no variable for the index"),
+        }
+    }
+}
@@ -5,14 +5,23 @@
 //! use rustplex::prelude::*;
 //! ```
 
-pub use crate::common::expression::LinearExpr;
+pub use crate::common::expression::{quicksum, GenericLinearExpr, LinearExpr};
+pub use crate::common::scalar::Scalar;
 
 pub use crate::modeling::constraint::ConstraintKey;
 pub use crate::modeling::model::Model;
 pub use crate::modeling::objective::ObjectiveSense::{self, Maximize, Minimize};
+pub use crate::modeling::solution_writer::SolutionFormat;
 pub use crate::modeling::variable::VariableKey;
+pub use crate::modeling::variable_family::VariableFamily;
 
-pub use crate::solver::config::SolverConfig;
+pub use crate::solver::callback::{CallbackAction, SolverEvent, SolverPhase};
+pub use crate::solver::cancellation::CancellationToken;
+pub use crate::solver::config::{SolverAlgorithm, SolverConfig};
+#[cfg(feature = "exact")]
+pub use crate::solver::exact::solution::ExactSolution;
 pub use crate::solver::status::SolverStatus;
 
 pub use crate::error::SolverError;
+
+pub use crate::{constraint, lp};
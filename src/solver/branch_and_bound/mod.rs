@@ -1 +1 @@
-// This is a placeholder for future branch and bound solver.
+pub mod solver;
@@ -0,0 +1,317 @@
+use crate::{
+    common::scalar::Scalar,
+    error::SolverError,
+    modeling::{
+        model::Model,
+        objective::ObjectiveSense,
+        sos::SpecialOrderedSet,
+        variable::{VariableKey, VariableType},
+    },
+    solver::{
+        callback::{CallbackAction, SolverCallback, SolverEvent},
+        config::SolverConfig,
+        solution::SolverSolution,
+        status::SolverStatus,
+    },
+    standard_form::{model::StandardModel, standardizer::Standardizer},
+};
+use std::time::Instant;
+
+/// Depth-first branch-and-bound over the special ordered sets, indicator constraints and
+/// semi-continuous variables of a model.
+///
+/// Every node solves the LP relaxation with the bounds of some variables tightened. A node
+/// whose point has a fractional indicator variable is split by fixing it at `0` and at `1`.
+/// A semi-continuous variable strictly between `0` and its bounds is fixed at `0` or
+/// restricted to its bounds, and a fractional semi-integer one is rounded down or up. A
+/// node whose point violates a set is split in two by fixing either the members above or
+/// below the weighted average of the point at zero, so the search branches on whole sets
+/// instead of on individual variables.
+pub struct BranchAndBound<'a> {
+    model: &'a Model,
+    standardizer: &'a Standardizer,
+    root: StandardModel,
+    sets: Vec<&'a SpecialOrderedSet>,
+    config: SolverConfig,
+    iteration_count: u32,
+    start_time: Instant,
+}
+
+/// Tightened `(variable, lower, upper)` bounds of a node of the search.
+type Bounds = Vec<(VariableKey, f64, f64)>;
+
+impl<'a> BranchAndBound<'a> {
+    /// Distance to an integer up to which a variable is integral, distance up to which a
+    /// variable is within its bounds, and magnitude up to which a variable is zero.
+    const INTEGRALITY_TOLERANCE: f64 = 1e-6;
+
+    /// Creates a search that enforces `sets`: the special ordered sets of the model and
+    /// those of its auxiliary functions that the LP relaxation does not keep.
+    pub fn new(
+        model: &'a Model,
+        standardizer: &'a Standardizer,
+        root: StandardModel,
+        sets: Vec<&'a SpecialOrderedSet>,
+    ) -> Self {
+        Self {
+            model,
+            standardizer,
+            root,
+            sets,
+            config: model.config().clone(),
+            iteration_count: 0,
+            start_time: Instant::now(),
+        }
+    }
+
+    pub fn start<S: Scalar>(
+        &mut self,
+        callback: &mut dyn SolverCallback,
+    ) -> Result<SolverSolution<VariableKey>, SolverError> {
+        self.start_time = Instant::now();
+        let objective_sign = match self.model.objective().unwrap().sense() {
+            ObjectiveSense::Maximize => 1.0,
+            ObjectiveSense::Minimize => -1.0,
+        };
+        let (tolerance, _, _) = self.config.tolerances();
+
+        let mut incumbent: Option<SolverSolution<VariableKey>> = None;
+        let mut best_value = f64::NEG_INFINITY;
+        let mut nodes: Vec<Bounds> = vec![Vec::new()];
+        let mut node = 0;
+
+        while let Some(bounds) = nodes.pop() {
+            if let Some(status) = self.limit_status() {
+                return Ok(self.finish(incumbent, status));
+            }
+
+            let solution = self.solve_node::<S>(node, &bounds, callback)?;
+            let mut action = CallbackAction::Continue;
+            match solution.status() {
+                SolverStatus::Optimal | SolverStatus::Infeasible => {}
+                // An unbounded relaxation or a limit ends the search
+                &status => {
+                    let best = match status {
+                        SolverStatus::Unbounded => Some(solution),
+                        _ => incumbent,
+                    };
+                    return Ok(self.finish(best, status));
+                }
+            }
+
+            let bound = match solution.status() {
+                SolverStatus::Optimal => *solution.objective_value(),
+                _ => None,
+            };
+            if let Some(objective_value) = bound {
+                // Prune nodes whose relaxation cannot improve on the incumbent
+                let value = objective_sign * objective_value;
+                let pruned = incumbent.is_some()
+                    && value <= best_value + tolerance * (1.0 + best_value.abs());
+                if !pruned {
+                    match self.branch(&solution) {
+                        None => {
+                            best_value = value;
+                            incumbent = Some(solution);
+                            action = callback.on_event(&SolverEvent::NewIncumbent {
+                                node,
+                                iteration: self.iteration_count,
+                                objective_value,
+                            });
+                        }
+                        Some(branches) => {
+                            // Push in reverse so that the first branch is explored first
+                            for branch in branches.into_iter().rev() {
+                                if let Some(child) = tighten(&bounds, branch) {
+                                    nodes.push(child);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            let event = SolverEvent::NodeProcessed {
+                node,
+                iteration: self.iteration_count,
+                bound,
+                incumbent: incumbent
+                    .as_ref()
+                    .and_then(|solution| *solution.objective_value()),
+                open_nodes: nodes.len(),
+            };
+            if action == CallbackAction::Terminate
+                || callback.on_event(&event) == CallbackAction::Terminate
+            {
+                return Ok(self.finish(incumbent, SolverStatus::Interrupted));
+            }
+            node += 1;
+        }
+
+        Ok(self.finish(incumbent, SolverStatus::Optimal))
+    }
+
+    /// Returns the bounds added by each child node, or `None` if the point satisfies every
+    /// indicator, semi-continuous variable and set.
+    fn branch(&self, solution: &SolverSolution<VariableKey>) -> Option<Vec<Bounds>> {
+        let tolerance = Self::INTEGRALITY_TOLERANCE;
+        let fix = |var_key: VariableKey, value: f64| vec![(var_key, value, value)];
+
+        for indicator in self.model.indicator_constraints().values() {
+            let binary = indicator.binary();
+            let value = solution.value(binary);
+            if value.min(1.0 - value) > tolerance {
+                // Explore the nearer value first
+                let (near, far) = if value >= 0.5 { (1.0, 0.0) } else { (0.0, 1.0) };
+                return Some(vec![fix(binary, near), fix(binary, far)]);
+            }
+        }
+
+        for (var_key, variable) in self.model.variables() {
+            if !variable.is_semi() {
+                continue;
+            }
+            let value = solution.value(var_key);
+            let (lower, upper) = (variable.lower_bound(), variable.upper_bound());
+            let outside = (lower - value).max(value - upper);
+            if value.abs() > tolerance && outside > tolerance {
+                let within = vec![(var_key, lower, upper)];
+                return Some(if value.abs() <= outside {
+                    vec![fix(var_key, 0.0), within]
+                } else {
+                    vec![within, fix(var_key, 0.0)]
+                });
+            }
+            let fractional = (value - value.round()).abs() > tolerance;
+            if matches!(variable.var_type(), VariableType::SemiInteger) && fractional {
+                return Some(vec![
+                    vec![(var_key, f64::NEG_INFINITY, value.floor())],
+                    vec![(var_key, value.ceil(), f64::INFINITY)],
+                ]);
+            }
+        }
+
+        let zeros = |vars: Vec<VariableKey>| vars.into_iter().map(|var| (var, 0.0, 0.0)).collect();
+        self.sets.iter().find_map(|set| {
+            set.branch(|var_key| solution.value(var_key), tolerance)
+                .map(|(left, right)| vec![zeros(left), zeros(right)])
+        })
+    }
+
+    fn solve_node<S: Scalar>(
+        &mut self,
+        node: u32,
+        bounds: &[(VariableKey, f64, f64)],
+        callback: &mut dyn SolverCallback,
+    ) -> Result<SolverSolution<VariableKey>, SolverError> {
+        // Every node continues the same search, within what is left of its budget
+        let config = self
+            .config
+            .remaining(self.start_time.elapsed(), self.iteration_count);
+        let mut relaxation = self.root.clone().with_config(config);
+        for &(var_key, lower, upper) in bounds {
+            self.standardizer
+                .restrict(var_key, lower, upper, &mut relaxation);
+        }
+        let mut callback = NodeCallback {
+            inner: callback,
+            root: node == 0,
+            iteration_offset: self.iteration_count,
+        };
+        let std_solution = relaxation.solve_with_callback::<S>(&mut callback)?;
+        self.iteration_count += *std_solution.iterations();
+        Ok(self
+            .standardizer
+            .reconstruct_solution(&std_solution, self.model))
+    }
+
+    /// Reports the best solution found with the iterations and time of the whole search.
+    fn finish(
+        &self,
+        incumbent: Option<SolverSolution<VariableKey>>,
+        status: SolverStatus,
+    ) -> SolverSolution<VariableKey> {
+        let elapsed = self.start_time.elapsed();
+        match incumbent {
+            Some(solution) => {
+                let iterations = self.iteration_count - *solution.iterations();
+                let solve_time = elapsed.saturating_sub(*solution.solve_time());
+                solution
+                    .with_status(status)
+                    .with_preceding_work(iterations, solve_time)
+            }
+            None if status == SolverStatus::Optimal => {
+                SolverSolution::new_infeasible(self.iteration_count, elapsed)
+            }
+            None => SolverSolution::new_without_values(status, self.iteration_count, elapsed),
+        }
+    }
+
+    fn limit_status(&self) -> Option<SolverStatus> {
+        if matches!(self.config.time_limit, Some(limit) if self.start_time.elapsed() >= limit) {
+            Some(SolverStatus::TimeLimitReached)
+        } else if self.iteration_count >= self.config.max_iterations {
+            Some(SolverStatus::MaxIterationsReached)
+        } else if self
+            .config
+            .cancellation_token
+            .as_ref()
+            .map_or(false, |token| token.is_cancelled())
+        {
+            Some(SolverStatus::Interrupted)
+        } else {
+            None
+        }
+    }
+}
+
+/// Forwards the events of a node relaxation as part of the whole search: iterations count
+/// on from the preceding nodes, and only the root reports its phase changes.
+struct NodeCallback<'a> {
+    inner: &'a mut dyn SolverCallback,
+    root: bool,
+    iteration_offset: u32,
+}
+
+impl SolverCallback for NodeCallback<'_> {
+    fn on_event(&mut self, event: &SolverEvent) -> CallbackAction {
+        let mut event = *event;
+        match &mut event {
+            SolverEvent::PhaseChanged { .. } if !self.root => return CallbackAction::Continue,
+            SolverEvent::PhaseChanged { iteration, .. }
+            | SolverEvent::Progress { iteration, .. }
+            | SolverEvent::NewIncumbent { iteration, .. }
+            | SolverEvent::NodeProcessed { iteration, .. } => *iteration += self.iteration_offset,
+        }
+        self.inner.on_event(&event)
+    }
+}
+
+/// Intersects the bounds of a node with those of a branch.
+///
+/// `None` if the branch tightens nothing, which would repeat the node, or if it leaves a
+/// variable without feasible values.
+fn tighten(bounds: &[(VariableKey, f64, f64)], branch: Bounds) -> Option<Bounds> {
+    let mut child = bounds.to_vec();
+    let mut tightened = false;
+    for (var_key, lower, upper) in branch {
+        match child
+            .iter_mut()
+            .find(|(existing, _, _)| *existing == var_key)
+        {
+            Some((_, old_lower, old_upper)) => {
+                tightened |= lower > *old_lower || upper < *old_upper;
+                *old_lower = old_lower.max(lower);
+                *old_upper = old_upper.min(upper);
+                if *old_lower > *old_upper {
+                    return None;
+                }
+            }
+            None => {
+                tightened = true;
+                child.push((var_key, lower, upper));
+            }
+        }
+    }
+    tightened.then_some(child)
+}
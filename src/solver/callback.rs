@@ -0,0 +1,116 @@
+/// The phases of the solver algorithms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverPhase {
+    /// Simplex: searching for a feasible basis through the auxiliary problem.
+    PhaseOne,
+    /// Simplex: optimizing the original objective from a feasible basis.
+    PhaseTwo,
+    /// Interior point: following the central path towards an optimal solution.
+    Barrier,
+}
+
+/// Events reported to a [`SolverCallback`] while a solve is running.
+///
+/// Iterations count the whole solve: under branch-and-bound they add up the iterations of
+/// every node relaxation, and only the root relaxation reports its phase changes.
+/// Objective values and bounds are in the sense of the model objective.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SolverEvent {
+    /// The solver entered a new phase.
+    PhaseChanged { phase: SolverPhase, iteration: u32 },
+    /// Periodic progress report, emitted every `SolverConfig::progress_interval` iterations.
+    Progress {
+        phase: SolverPhase,
+        iteration: u32,
+        /// The current objective value. During Phase 1 this is the auxiliary objective.
+        objective_value: f64,
+        /// How far the current point is from satisfying all constraints (zero in Phase 2).
+        /// For the barrier this is the largest constraint violation of the current iterate.
+        primal_infeasibility: f64,
+    },
+    /// Branch-and-bound found a solution better than every one before it.
+    NewIncumbent {
+        node: u32,
+        iteration: u32,
+        objective_value: f64,
+    },
+    /// Branch-and-bound finished a node of the search, numbered from `0` for the root.
+    NodeProcessed {
+        node: u32,
+        iteration: u32,
+        /// The objective value of the node relaxation, which bounds every solution found
+        /// below the node (`None` if the relaxation is infeasible).
+        bound: Option<f64>,
+        /// The objective value of the best solution found so far.
+        incumbent: Option<f64>,
+        /// The number of nodes left to explore.
+        open_nodes: usize,
+    },
+}
+
+/// What the solver should do after a callback has handled an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallbackAction {
+    /// Keep solving.
+    Continue,
+    /// Stop as soon as possible with `SolverStatus::Interrupted`.
+    Terminate,
+}
+
+/// Receives [`SolverEvent`]s during a solve and may request early termination.
+///
+/// Implemented for any `FnMut(&SolverEvent) -> CallbackAction` closure.
+pub trait SolverCallback {
+    fn on_event(&mut self, event: &SolverEvent) -> CallbackAction;
+}
+
+impl<F: FnMut(&SolverEvent) -> CallbackAction> SolverCallback for F {
+    fn on_event(&mut self, event: &SolverEvent) -> CallbackAction {
+        self(event)
+    }
+}
+
+/// A callback that ignores every event.
+pub(crate) struct NoCallback;
+
+impl SolverCallback for NoCallback {
+    fn on_event(&mut self, _event: &SolverEvent) -> CallbackAction {
+        CallbackAction::Continue
+    }
+}
+
+/// Translates events of the standardized (maximization) model back to the sense of the
+/// original objective before forwarding them.
+pub(crate) struct ObjectiveSenseCallback<'a> {
+    inner: &'a mut dyn SolverCallback,
+    objective_sign: f64,
+}
+
+impl<'a> ObjectiveSenseCallback<'a> {
+    pub(crate) fn new(inner: &'a mut dyn SolverCallback, objective_sign: f64) -> Self {
+        Self {
+            inner,
+            objective_sign,
+        }
+    }
+}
+
+impl SolverCallback for ObjectiveSenseCallback<'_> {
+    fn on_event(&mut self, event: &SolverEvent) -> CallbackAction {
+        match *event {
+            // The Phase 1 objective is auxiliary and has no sense to translate
+            SolverEvent::Progress {
+                phase,
+                iteration,
+                objective_value,
+                primal_infeasibility,
+            } if phase != SolverPhase::PhaseOne => self.inner.on_event(&SolverEvent::Progress {
+                phase,
+                iteration,
+                objective_value: self.objective_sign * objective_value,
+                primal_infeasibility,
+            }),
+            _ => self.inner.on_event(event),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// A shareable handle used to cooperatively abort a running solve.
+///
+/// Clones share the same flag, so a token can be handed to a solver through
+/// `SolverConfig` while another thread keeps a clone to call [`cancel`](Self::cancel).
+/// The solver polls the token between pivots and stops with `SolverStatus::Interrupted`.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new, non-cancelled token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation of every solve observing this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Clears the cancellation request so the token can be reused.
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::Relaxed);
+    }
+}
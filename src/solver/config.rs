@@ -1,20 +1,124 @@
+use crate::solver::cancellation::CancellationToken;
+use std::time::Duration;
+
+/// The algorithm used to solve linear programs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SolverAlgorithm {
+    /// Two-Phase Simplex method on a slack dictionary.
+    #[default]
+    Simplex,
+    /// Mehrotra predictor-corrector primal-dual interior-point (barrier) method.
+    InteriorPoint,
+}
+
 /// Configuration settings for the Simplex Solver.
 ///
 /// This struct holds parameters that control the behavior of the optimization algorithm,
 /// such as stopping criteria and numerical precision.
-#[derive(Debug, Clone, Copy)]
+///
+/// It is `Clone` but no longer `Copy`, since the `cancellation_token` is shared with the
+/// caller; use `.clone()` where a copy was made before.
+#[derive(Debug, Clone)]
 pub struct SolverConfig {
-    /// Maximum number of iterations before stopping (Default: 10000).
+    /// Maximum number of iterations before stopping (Default: 10000). Like `time_limit`,
+    /// the budget is shared by the interior-point method and its crossover.
     pub max_iterations: u32,
-    /// Numerical tolerance for floating-point comparisons (Default: 1e-10).
+    /// Magnitude at or below which coefficients are treated as zero: the model drops them
+    /// from constraints and objectives as they are added, and the simplex from its
+    /// dictionary (Default: 1e-14).
+    pub drop_tolerance: f64,
+    /// Largest bound violation a basic solution may have and still count as feasible
+    /// (Default: 1e-10).
+    pub primal_feasibility_tolerance: f64,
+    /// Largest improving objective coefficient at which a basis counts as optimal
+    /// (Default: 1e-10).
+    pub dual_feasibility_tolerance: f64,
+    /// Smallest coefficient magnitude accepted as a pivot element in the ratio test
+    /// (Default: 1e-10).
+    pub pivot_tolerance: f64,
+    /// Former single tolerance (Default: 1e-10).
+    ///
+    /// A value other than its default overrides the primal feasibility, dual feasibility
+    /// and pivot tolerances.
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `primal_feasibility_tolerance`, `dual_feasibility_tolerance` and `pivot_tolerance`"
+    )]
     pub tolerance: f64,
+    /// Wall-clock budget for a solve (Default: None, no limit).
+    ///
+    /// The budget covers the whole solve: the crossover only gets the time the
+    /// interior-point method left over.
+    ///
+    /// When exceeded, the solver stops with `SolverStatus::TimeLimitReached` and returns
+    /// the best point found so far, if any. The simplex returns its current basis once it
+    /// is feasible; the interior-point method returns its current iterate, which may still
+    /// violate the constraints slightly.
+    pub time_limit: Option<Duration>,
+    /// Token polled between pivots to abort the solve (Default: None).
+    ///
+    /// When cancelled, the solver stops with `SolverStatus::Interrupted` and returns
+    /// the best point found so far, if any.
+    pub cancellation_token: Option<CancellationToken>,
+    /// Number of iterations between `SolverEvent::Progress` reports (Default: 100).
+    /// A value of 0 disables progress reports.
+    pub progress_interval: u32,
+    /// Algorithm used to solve the model (Default: `SolverAlgorithm::Simplex`).
+    pub algorithm: SolverAlgorithm,
+    /// Relative duality gap and residual norm at which the interior-point method
+    /// declares a solution optimal (Default: 1e-8).
+    pub duality_gap_tolerance: f64,
+    /// Whether an optimal interior-point solution is moved to an optimal vertex with
+    /// a simplex basis, giving basic solutions and simplex duals (Default: true).
+    pub crossover: bool,
+}
+
+impl SolverConfig {
+    /// Default of the deprecated `tolerance` field.
+    const DEFAULT_TOLERANCE: f64 = 1e-10;
+
+    /// Returns the primal feasibility, dual feasibility and pivot tolerances, overridden by
+    /// the deprecated `tolerance` field when it is set.
+    #[allow(deprecated)]
+    pub(crate) fn tolerances(&self) -> (f64, f64, f64) {
+        if self.tolerance != Self::DEFAULT_TOLERANCE {
+            (self.tolerance, self.tolerance, self.tolerance)
+        } else {
+            (
+                self.primal_feasibility_tolerance,
+                self.dual_feasibility_tolerance,
+                self.pivot_tolerance,
+            )
+        }
+    }
+
+    /// Returns this configuration with the budget left after `elapsed` time and
+    /// `iterations` iterations, for a phase that continues an earlier one.
+    pub(crate) fn remaining(&self, elapsed: Duration, iterations: u32) -> Self {
+        Self {
+            max_iterations: self.max_iterations.saturating_sub(iterations),
+            time_limit: self.time_limit.map(|limit| limit.saturating_sub(elapsed)),
+            ..self.clone()
+        }
+    }
 }
 
 impl Default for SolverConfig {
+    #[allow(deprecated)]
     fn default() -> Self {
         Self {
             max_iterations: 10_000,
-            tolerance: 1e-10,
+            drop_tolerance: 1e-14,
+            primal_feasibility_tolerance: 1e-10,
+            dual_feasibility_tolerance: 1e-10,
+            pivot_tolerance: 1e-10,
+            tolerance: Self::DEFAULT_TOLERANCE,
+            time_limit: None,
+            cancellation_token: None,
+            progress_interval: 100,
+            algorithm: SolverAlgorithm::Simplex,
+            duality_gap_tolerance: 1e-8,
+            crossover: true,
         }
     }
 }
@@ -0,0 +1,3 @@
+pub mod rational;
+pub mod solution;
+pub mod solver;
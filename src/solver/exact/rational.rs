@@ -0,0 +1,32 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::Zero;
+
+/// Converts a finite float to the rational number of its shortest decimal representation.
+///
+/// Model data is usually written in decimal, so `0.1` is read as `1/10` rather than as the
+/// nearest binary fraction `3602879701896397/36028797018963968`.
+pub fn to_rational(value: f64) -> BigRational {
+    debug_assert!(value.is_finite(), "cannot convert {} to a rational", value);
+    if value == 0.0 {
+        return BigRational::zero();
+    }
+
+    // `Display` for f64 prints the shortest round-trip decimal without an exponent
+    let text = value.abs().to_string();
+    let (integer, fraction) = match text.split_once('.') {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (text.as_str(), ""),
+    };
+
+    let digits = format!("{}{}", integer, fraction);
+    let numerator: BigInt = digits.parse().unwrap_or_else(|_| BigInt::zero());
+    let denominator = num_traits::pow(BigInt::from(10), fraction.len());
+    let magnitude = BigRational::new(numerator, denominator);
+
+    if value < 0.0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    modeling::{constraint::ConstraintKey, variable::VariableKey},
+    solver::status::SolverStatus,
+};
+use num_rational::BigRational;
+use num_traits::{ToPrimitive, Zero};
+use slotmap::SecondaryMap;
+use std::{fmt, time};
+
+/// The result of an exact (rational arithmetic) solve.
+///
+/// All values are exact rationals of the model data, where every coefficient, bound and
+/// right hand side is read as its shortest decimal representation.
+#[derive(Debug, Clone)]
+pub struct ExactSolution {
+    status: SolverStatus,
+    objective_value: Option<BigRational>,
+    variable_values: Option<SecondaryMap<VariableKey, BigRational>>,
+    dual_values: Option<SecondaryMap<ConstraintKey, BigRational>>,
+    reduced_costs: Option<SecondaryMap<VariableKey, BigRational>>,
+    iterations: u32,
+    solve_time: time::Duration,
+}
+
+impl ExactSolution {
+    pub(crate) fn new(
+        status: SolverStatus,
+        objective_value: BigRational,
+        variable_values: SecondaryMap<VariableKey, BigRational>,
+        iterations: u32,
+        solve_time: time::Duration,
+    ) -> Self {
+        Self {
+            status,
+            objective_value: Some(objective_value),
+            variable_values: Some(variable_values),
+            dual_values: None,
+            reduced_costs: None,
+            iterations,
+            solve_time,
+        }
+    }
+
+    pub(crate) fn new_without_values(
+        status: SolverStatus,
+        iterations: u32,
+        solve_time: time::Duration,
+    ) -> Self {
+        Self {
+            status,
+            objective_value: None,
+            variable_values: None,
+            dual_values: None,
+            reduced_costs: None,
+            iterations,
+            solve_time,
+        }
+    }
+
+    pub(crate) fn with_dual_values(
+        mut self,
+        dual_values: SecondaryMap<ConstraintKey, BigRational>,
+        reduced_costs: SecondaryMap<VariableKey, BigRational>,
+    ) -> Self {
+        self.dual_values = Some(dual_values);
+        self.reduced_costs = Some(reduced_costs);
+        self
+    }
+
+    /// Returns the final status of the solver (e.g., Optimal, Infeasible).
+    pub fn status(&self) -> &SolverStatus {
+        &self.status
+    }
+
+    /// Returns the exact objective value, if a solution was found.
+    pub fn objective_value(&self) -> Option<&BigRational> {
+        self.objective_value.as_ref()
+    }
+
+    pub fn variable_values(&self) -> Option<&SecondaryMap<VariableKey, BigRational>> {
+        self.variable_values.as_ref()
+    }
+
+    /// Returns the exact dual values (shadow prices) of the constraints.
+    pub fn dual_values(&self) -> Option<&SecondaryMap<ConstraintKey, BigRational>> {
+        self.dual_values.as_ref()
+    }
+
+    /// Returns the exact reduced costs of the variables.
+    pub fn reduced_costs(&self) -> Option<&SecondaryMap<VariableKey, BigRational>> {
+        self.reduced_costs.as_ref()
+    }
+
+    /// Returns the number of simplex iterations performed.
+    pub fn iterations(&self) -> &u32 {
+        &self.iterations
+    }
+
+    /// Returns the time taken to solve the problem.
+    pub fn solve_time(&self) -> &time::Duration {
+        &self.solve_time
+    }
+
+    /// Returns the exact value of a specific variable.
+    ///
+    /// Returns zero if the variable is not found in the solution.
+    pub fn value(&self, var_key: VariableKey) -> BigRational {
+        Self::lookup(&self.variable_values, var_key)
+    }
+
+    /// Returns the exact dual value of a specific constraint.
+    ///
+    /// Returns zero if no dual information is available.
+    pub fn dual(&self, constr_key: ConstraintKey) -> BigRational {
+        Self::lookup(&self.dual_values, constr_key)
+    }
+
+    /// Returns the exact reduced cost of a specific variable.
+    ///
+    /// Returns zero if no dual information is available.
+    pub fn reduced_cost(&self, var_key: VariableKey) -> BigRational {
+        Self::lookup(&self.reduced_costs, var_key)
+    }
+
+    fn lookup<K: slotmap::Key>(map: &Option<SecondaryMap<K, BigRational>>, key: K) -> BigRational {
+        map.as_ref()
+            .and_then(|map| map.get(key))
+            .cloned()
+            .unwrap_or_else(BigRational::zero)
+    }
+}
+
+impl fmt::Display for ExactSolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Solver Status: {:?}", self.status)?;
+        match &self.objective_value {
+            Some(value) => writeln!(
+                f,
+                "Objective Value: {} (≈ {})",
+                value,
+                value.to_f64().unwrap_or(f64::NAN)
+            )?,
+            None => writeln!(f, "Objective Value: None")?,
+        }
+
+        if let Some(ref vars) = self.variable_values {
+            writeln!(f, "Variable Values: [")?;
+            for (var, value) in vars {
+                writeln!(f, "\t{}: {}", var, value)?;
+            }
+            writeln!(f, "]")?;
+        } else {
+            writeln!(f, "Variable Values: None")?;
+        }
+        writeln!(f, "Iterations: {}", self.iterations)?;
+        write!(f, "Solve Time: {:.2?}", self.solve_time)?;
+        Ok(())
+    }
+}
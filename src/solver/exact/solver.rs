@@ -0,0 +1,568 @@
+use crate::{
+    common::expression::LinearExpr,
+    modeling::{
+        constraint::{Constraint, ConstraintKey, ConstraintSense},
+        model::Model,
+        objective::ObjectiveSense,
+        variable::VariableKey,
+    },
+    solver::{
+        config::SolverConfig,
+        exact::{rational::to_rational, solution::ExactSolution},
+        status::SolverStatus,
+    },
+};
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use slotmap::SecondaryMap;
+use std::time::Instant;
+
+/// How a model variable is expressed through non-negative tableau columns.
+enum ColumnMapping {
+    /// `x = shift + column`
+    Shifted { column: usize, shift: BigRational },
+    /// `x = shift - column`
+    Mirrored { column: usize, shift: BigRational },
+    /// `x = pos - neg`
+    Split { pos: usize, neg: usize },
+}
+
+/// A row of the problem before slack and artificial columns are added.
+struct ProblemRow {
+    coefficients: Vec<BigRational>,
+    sense: ConstraintSense,
+    rhs: BigRational,
+    /// The model constraint this row comes from, `None` for variable bounds.
+    constraint: Option<ConstraintKey>,
+}
+
+/// Dense two-phase simplex in exact rational arithmetic.
+///
+/// The model is translated to rationals directly, without going through the floating-point
+/// `StandardModel`, and solved with Bland's rule, which cannot cycle.
+pub struct ExactSimplexSolver<'a> {
+    model: &'a Model,
+    mapping: SecondaryMap<VariableKey, ColumnMapping>,
+    problem_rows: Vec<ProblemRow>,
+    /// Maximization costs of the structural columns.
+    costs: Vec<BigRational>,
+    config: SolverConfig,
+    iteration_count: u32,
+    start_time: Instant,
+}
+
+/// The simplex tableau. Every row (and the objective row) stores its right hand side last.
+struct Tableau {
+    rows: Vec<Vec<BigRational>>,
+    basis: Vec<usize>,
+    /// Reduced costs `d_j`, followed by the negated objective value.
+    objective: Vec<BigRational>,
+    /// Columns that may enter the basis.
+    eligible: Vec<bool>,
+    /// The slack or artificial column that was initially basic in each row.
+    identity_columns: Vec<usize>,
+}
+
+impl Tableau {
+    fn rhs(&self, row: usize) -> &BigRational {
+        self.rows[row].last().unwrap()
+    }
+
+    fn objective_value(&self) -> BigRational {
+        -self.objective.last().unwrap()
+    }
+
+    fn pivot(&mut self, pivot_row: usize, column: usize) {
+        let pivot = self.rows[pivot_row][column].clone();
+        self.rows[pivot_row]
+            .iter_mut()
+            .for_each(|value| *value /= &pivot);
+
+        let normalized = self.rows[pivot_row].clone();
+        let eliminate = |row: &mut Vec<BigRational>| {
+            let factor = row[column].clone();
+            if !factor.is_zero() {
+                row.iter_mut()
+                    .zip(&normalized)
+                    .for_each(|(value, pivot_value)| *value -= &factor * pivot_value);
+            }
+        };
+
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            if index != pivot_row {
+                eliminate(row);
+            }
+        }
+        eliminate(&mut self.objective);
+        self.basis[pivot_row] = column;
+    }
+
+    /// Bland's rule: the eligible column with the smallest index and positive reduced cost.
+    fn entering_column(&self) -> Option<usize> {
+        (0..self.eligible.len())
+            .find(|&column| self.eligible[column] && self.objective[column].is_positive())
+    }
+
+    /// Minimum ratio test, breaking ties by the smallest basic column (Bland's rule).
+    fn leaving_row(&self, column: usize) -> Option<usize> {
+        let mut best: Option<(usize, BigRational)> = None;
+        for (index, row) in self.rows.iter().enumerate() {
+            if !row[column].is_positive() {
+                continue;
+            }
+            let ratio = self.rhs(index) / &row[column];
+            let better = match &best {
+                None => true,
+                Some((best_index, best_ratio)) => {
+                    ratio < *best_ratio
+                        || (ratio == *best_ratio && self.basis[index] < self.basis[*best_index])
+                }
+            };
+            if better {
+                best = Some((index, ratio));
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+}
+
+impl<'a> ExactSimplexSolver<'a> {
+    pub fn from_model(model: &'a Model) -> Self {
+        let mut column_count = 0;
+        let mut next_column = || {
+            column_count += 1;
+            column_count - 1
+        };
+
+        let mut mapping = SecondaryMap::new();
+        let mut bounds = Vec::new();
+        for (var_key, var) in model.variables() {
+            let (lb, ub) = (var.lower_bound(), var.upper_bound());
+            let var_mapping = match (lb.is_finite(), ub.is_finite()) {
+                (true, _) => {
+                    let column = next_column();
+                    if ub.is_finite() {
+                        bounds.push((column, to_rational(ub) - to_rational(lb)));
+                    }
+                    ColumnMapping::Shifted {
+                        column,
+                        shift: to_rational(lb),
+                    }
+                }
+                (false, true) => ColumnMapping::Mirrored {
+                    column: next_column(),
+                    shift: to_rational(ub),
+                },
+                (false, false) => ColumnMapping::Split {
+                    pos: next_column(),
+                    neg: next_column(),
+                },
+            };
+            mapping.insert(var_key, var_mapping);
+        }
+
+        let mut solver = Self {
+            model,
+            mapping,
+            problem_rows: Vec::new(),
+            costs: Vec::new(),
+            config: model.config().clone(),
+            iteration_count: 0,
+            start_time: Instant::now(),
+        };
+
+        let column_count = column_count;
+        for (column, upper_bound) in bounds {
+            let mut coefficients = vec![BigRational::zero(); column_count];
+            coefficients[column] = BigRational::one();
+            solver.problem_rows.push(ProblemRow {
+                coefficients,
+                sense: ConstraintSense::LessEqual,
+                rhs: upper_bound,
+                constraint: None,
+            });
+        }
+
+        for (constr_key, constr) in model.constraints() {
+            // lhs - rhs (sense) 0
+            let (coefficients, constant) = solver.translate(
+                Self::constraint_terms(constr),
+                to_rational(constr.lhs().constant) - to_rational(constr.rhs().constant),
+                column_count,
+            );
+            if let ConstraintSense::Range = constr.sense() {
+                // lhs - rhs >= lower - upper is the second side of a range
+                solver.problem_rows.push(ProblemRow {
+                    coefficients: coefficients.clone(),
+                    sense: ConstraintSense::GreaterEqual,
+                    rhs: -&constant - to_rational(constr.range_width()),
+                    constraint: Some(constr_key),
+                });
+                solver.problem_rows.push(ProblemRow {
+                    coefficients,
+                    sense: ConstraintSense::LessEqual,
+                    rhs: -constant,
+                    constraint: Some(constr_key),
+                });
+                continue;
+            }
+            solver.problem_rows.push(ProblemRow {
+                coefficients,
+                sense: constr.sense(),
+                rhs: -constant,
+                constraint: Some(constr_key),
+            });
+        }
+
+        let objective = model.objective().unwrap();
+        let (costs, _) = solver.translate(
+            Self::terms(objective.expr()),
+            BigRational::zero(),
+            column_count,
+        );
+        solver.costs = match objective.sense() {
+            ObjectiveSense::Maximize => costs,
+            ObjectiveSense::Minimize => costs.into_iter().map(|cost| -cost).collect(),
+        };
+
+        solver
+    }
+
+    pub fn start(&mut self) -> ExactSolution {
+        self.start_time = Instant::now();
+        let mut tableau = self.build_tableau();
+
+        // Phase 1: drive the artificial variables to zero
+        if tableau
+            .basis
+            .iter()
+            .any(|&column| !tableau.eligible[column])
+        {
+            let status = self.solve(&mut tableau);
+            if !status.is_optimal() {
+                return self.solution_without_values(status);
+            } else if tableau.objective_value().is_negative() {
+                return self.solution_without_values(SolverStatus::Infeasible);
+            }
+            Self::remove_artificials(&mut tableau);
+        }
+
+        // Phase 2: optimize the original objective
+        self.set_phase_two_objective(&mut tableau);
+        let status = self.solve(&mut tableau);
+        if !status.is_optimal() {
+            return self.solution_without_values(status);
+        }
+
+        self.optimal_solution(&tableau)
+    }
+
+    /// The coefficients of `lhs - rhs` of a constraint.
+    fn constraint_terms(
+        constr: &Constraint,
+    ) -> impl Iterator<Item = (VariableKey, BigRational)> + '_ {
+        let negated_rhs = Self::terms(constr.rhs()).map(|(var, coefficient)| (var, -coefficient));
+        Self::terms(constr.lhs()).chain(negated_rhs)
+    }
+
+    fn terms(
+        expression: &LinearExpr<VariableKey>,
+    ) -> impl Iterator<Item = (VariableKey, BigRational)> + '_ {
+        expression
+            .terms
+            .iter()
+            .map(|(var, coefficient)| (*var, to_rational(*coefficient)))
+    }
+
+    /// Rewrites a linear expression over model variables in terms of the tableau columns.
+    fn translate(
+        &self,
+        terms: impl Iterator<Item = (VariableKey, BigRational)>,
+        mut constant: BigRational,
+        column_count: usize,
+    ) -> (Vec<BigRational>, BigRational) {
+        let mut coefficients = vec![BigRational::zero(); column_count];
+        for (var, coefficient) in terms {
+            match &self.mapping[var] {
+                ColumnMapping::Shifted { column, shift } => {
+                    constant += &coefficient * shift;
+                    coefficients[*column] += coefficient;
+                }
+                ColumnMapping::Mirrored { column, shift } => {
+                    constant += &coefficient * shift;
+                    coefficients[*column] -= coefficient;
+                }
+                ColumnMapping::Split { pos, neg } => {
+                    coefficients[*neg] -= &coefficient;
+                    coefficients[*pos] += coefficient;
+                }
+            }
+        }
+        (coefficients, constant)
+    }
+
+    /// Builds the initial tableau with a slack for every inequality and an artificial
+    /// variable for every `>=` or `=` row, which have no obvious starting basic variable.
+    ///
+    /// Rows are negated as needed to make every right hand side non-negative.
+    fn build_tableau(&self) -> Tableau {
+        let senses: Vec<_> = self
+            .problem_rows
+            .iter()
+            .map(|row| match (row.sense, row.rhs.is_negative()) {
+                (ConstraintSense::LessEqual, true) => ConstraintSense::GreaterEqual,
+                (ConstraintSense::GreaterEqual, true) => ConstraintSense::LessEqual,
+                (sense, _) => sense,
+            })
+            .collect();
+
+        let structural = self.costs.len();
+        let width = structural
+            + senses
+                .iter()
+                .map(|sense| match sense {
+                    ConstraintSense::GreaterEqual => 2,
+                    _ => 1,
+                })
+                .sum::<usize>();
+
+        let mut rows = Vec::with_capacity(self.problem_rows.len());
+        let mut basis = Vec::with_capacity(self.problem_rows.len());
+        let mut eligible = vec![true; width];
+        let mut phase_one = vec![BigRational::zero(); width + 1];
+        let mut next_column = structural;
+
+        for (problem_row, sense) in self.problem_rows.iter().zip(senses) {
+            let negate = problem_row.rhs.is_negative();
+            let mut row: Vec<BigRational> = problem_row
+                .coefficients
+                .iter()
+                .map(|coefficient| {
+                    if negate {
+                        -coefficient
+                    } else {
+                        coefficient.clone()
+                    }
+                })
+                .collect();
+            row.resize(width + 1, BigRational::zero());
+            row[width] = problem_row.rhs.abs();
+
+            match sense {
+                ConstraintSense::LessEqual => {
+                    row[next_column] = BigRational::one();
+                    basis.push(next_column);
+                    next_column += 1;
+                }
+                ConstraintSense::Range => unreachable!("range constraints are split into two rows"),
+                ConstraintSense::GreaterEqual | ConstraintSense::Equal => {
+                    if matches!(sense, ConstraintSense::GreaterEqual) {
+                        row[next_column] = -BigRational::one();
+                        next_column += 1;
+                    }
+                    let artificial = next_column;
+                    next_column += 1;
+                    row[artificial] = BigRational::one();
+                    eligible[artificial] = false;
+                    basis.push(artificial);
+
+                    // Phase 1 maximizes the negated sum of the artificial variables
+                    for (objective, value) in phase_one.iter_mut().zip(&row) {
+                        *objective += value;
+                    }
+                    phase_one[artificial] = BigRational::zero();
+                }
+            }
+            rows.push(row);
+        }
+
+        Tableau {
+            identity_columns: basis.clone(),
+            rows,
+            basis,
+            objective: phase_one,
+            eligible,
+        }
+    }
+
+    /// Pivots zero-valued artificial variables out of the basis. Rows where this is not
+    /// possible are redundant and never change again, since their eligible entries are zero.
+    fn remove_artificials(tableau: &mut Tableau) {
+        for row in 0..tableau.rows.len() {
+            if tableau.eligible[tableau.basis[row]] {
+                continue;
+            }
+            let replacement = (0..tableau.eligible.len())
+                .find(|&column| tableau.eligible[column] && !tableau.rows[row][column].is_zero());
+            if let Some(column) = replacement {
+                tableau.pivot(row, column);
+            }
+        }
+    }
+
+    fn set_phase_two_objective(&self, tableau: &mut Tableau) {
+        let width = tableau.eligible.len();
+        let cost = |column: usize| {
+            self.costs
+                .get(column)
+                .cloned()
+                .unwrap_or_else(BigRational::zero)
+        };
+
+        let mut objective: Vec<BigRational> = (0..width).map(cost).collect();
+        objective.push(BigRational::zero());
+        for (row, &basic) in tableau.rows.iter().zip(&tableau.basis) {
+            let basic_cost = cost(basic);
+            if basic_cost.is_zero() {
+                continue;
+            }
+            for (value, entry) in objective.iter_mut().zip(row) {
+                *value -= &basic_cost * entry;
+            }
+        }
+        tableau.objective = objective;
+    }
+
+    fn solve(&mut self, tableau: &mut Tableau) -> SolverStatus {
+        loop {
+            let entering = match tableau.entering_column() {
+                Some(column) => column,
+                None => return SolverStatus::Optimal,
+            };
+            if self.iteration_count >= self.config.max_iterations {
+                return SolverStatus::MaxIterationsReached;
+            } else if self.is_time_limit_reached() {
+                return SolverStatus::TimeLimitReached;
+            } else if self.is_cancelled() {
+                return SolverStatus::Interrupted;
+            }
+            self.iteration_count += 1;
+
+            match tableau.leaving_row(entering) {
+                Some(row) => tableau.pivot(row, entering),
+                None => return SolverStatus::Unbounded,
+            }
+        }
+    }
+
+    fn optimal_solution(&self, tableau: &Tableau) -> ExactSolution {
+        let mut column_values = vec![BigRational::zero(); tableau.eligible.len()];
+        for (row, &basic) in tableau.basis.iter().enumerate() {
+            column_values[basic] = tableau.rhs(row).clone();
+        }
+
+        let variable_values: SecondaryMap<_, _> = self
+            .mapping
+            .iter()
+            .map(|(var_key, var_mapping)| {
+                let value = match var_mapping {
+                    ColumnMapping::Shifted { column, shift } => shift + &column_values[*column],
+                    ColumnMapping::Mirrored { column, shift } => shift - &column_values[*column],
+                    ColumnMapping::Split { pos, neg } => {
+                        &column_values[*pos] - &column_values[*neg]
+                    }
+                };
+                (var_key, value)
+            })
+            .collect();
+
+        let objective = self.model.objective().unwrap();
+        let objective_value = Self::evaluate(objective.expr(), &variable_values);
+
+        let dual_values = self.dual_values(tableau);
+        let reduced_costs = self.reduced_costs(&dual_values);
+
+        ExactSolution::new(
+            SolverStatus::Optimal,
+            objective_value,
+            variable_values,
+            self.iteration_count,
+            self.start_time.elapsed(),
+        )
+        .with_dual_values(dual_values, reduced_costs)
+    }
+
+    /// Reads the duals from the reduced costs of the slack and artificial columns, which
+    /// formed the initial identity basis: `y_i = -d_i`.
+    fn dual_values(&self, tableau: &Tableau) -> SecondaryMap<ConstraintKey, BigRational> {
+        let objective_sign = match self.model.objective().unwrap().sense() {
+            ObjectiveSense::Maximize => BigRational::one(),
+            ObjectiveSense::Minimize => -BigRational::one(),
+        };
+
+        // Both rows of a range constraint add up to its single dual
+        let mut dual_values = SecondaryMap::new();
+        for (problem_row, &column) in self.problem_rows.iter().zip(&tableau.identity_columns) {
+            let constr_key = match problem_row.constraint {
+                Some(constr_key) => constr_key,
+                None => continue,
+            };
+            // Undo the normalization of negative right hand sides
+            let row_sign = if problem_row.rhs.is_negative() {
+                -&objective_sign
+            } else {
+                objective_sign.clone()
+            };
+            let dual = -&tableau.objective[column] * row_sign;
+            match dual_values.get_mut(constr_key) {
+                Some(total) => *total += dual,
+                None => {
+                    dual_values.insert(constr_key, dual);
+                }
+            }
+        }
+        dual_values
+    }
+
+    /// `d_j = c_j - sum_i y_i * a_ij`, where `a_ij` is the coefficient of `x_j` in `lhs_i - rhs_i`.
+    fn reduced_costs(
+        &self,
+        dual_values: &SecondaryMap<ConstraintKey, BigRational>,
+    ) -> SecondaryMap<VariableKey, BigRational> {
+        let objective = self.model.objective().unwrap().expr();
+        let mut reduced_costs: SecondaryMap<_, _> = self
+            .model
+            .variables()
+            .keys()
+            .map(|var_key| (var_key, to_rational(objective.coefficient(&var_key))))
+            .collect();
+
+        for (constr_key, constr) in self.model.constraints() {
+            let dual = match dual_values.get(constr_key) {
+                Some(dual) if !dual.is_zero() => dual,
+                _ => continue,
+            };
+            for (var_key, coefficient) in Self::constraint_terms(constr) {
+                if let Some(reduced_cost) = reduced_costs.get_mut(var_key) {
+                    *reduced_cost -= dual * coefficient;
+                }
+            }
+        }
+        reduced_costs
+    }
+
+    fn evaluate(
+        expression: &LinearExpr<VariableKey>,
+        values: &SecondaryMap<VariableKey, BigRational>,
+    ) -> BigRational {
+        expression.terms.iter().fold(
+            to_rational(expression.constant),
+            |sum, (var, coefficient)| sum + to_rational(*coefficient) * &values[*var],
+        )
+    }
+
+    fn solution_without_values(&self, status: SolverStatus) -> ExactSolution {
+        ExactSolution::new_without_values(status, self.iteration_count, self.start_time.elapsed())
+    }
+
+    fn is_time_limit_reached(&self) -> bool {
+        matches!(self.config.time_limit, Some(limit) if self.start_time.elapsed() >= limit)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.config
+            .cancellation_token
+            .as_ref()
+            .map_or(false, |token| token.is_cancelled())
+    }
+}
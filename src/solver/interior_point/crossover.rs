@@ -0,0 +1,134 @@
+use crate::{
+    common::scalar::Scalar,
+    solver::{
+        callback::SolverCallback,
+        config::SolverConfig,
+        simplex::{
+            slack_dictionary::{
+                row::DictionaryRowKey, variable::DictionaryVariableKey, SlackDictionary,
+            },
+            solver::SimplexSolver,
+        },
+        solution::SolverSolution,
+    },
+    standard_form::{
+        constraint::StandardConstraintKey, model::StandardModel, variable::StandardVariableKey,
+    },
+};
+use std::collections::HashSet;
+
+/// Moves an optimal interior-point solution to an optimal vertex.
+///
+/// The variables that are clearly positive at the barrier solution (larger than their
+/// dual slack) are pivoted into a basis of the slack dictionary, largest first. The
+/// simplex solver then starts from that basis, which is usually optimal or a few pivots
+/// away from it, and provides a basic solution with simplex duals.
+pub struct Crossover<'a> {
+    standard_model: &'a StandardModel,
+    config: SolverConfig,
+}
+
+impl<'a> Crossover<'a> {
+    /// Smallest pivot accepted while building the basis.
+    const PIVOT_TOLERANCE: f64 = 1e-7;
+
+    pub fn new(standard_model: &'a StandardModel, config: SolverConfig) -> Self {
+        Self {
+            standard_model,
+            config,
+        }
+    }
+
+    pub fn start(
+        &self,
+        barrier_solution: &SolverSolution<StandardVariableKey, StandardConstraintKey>,
+        callback: &mut dyn SolverCallback,
+    ) -> SolverSolution<StandardVariableKey, StandardConstraintKey> {
+        let mut slack_dict = SlackDictionary::from_standard_model(
+            self.standard_model,
+            f64::tolerance(self.config.drop_tolerance),
+        );
+        let candidates = self.basis_candidates(&slack_dict, barrier_solution);
+        Self::build_basis(&mut slack_dict, candidates);
+
+        SimplexSolver::from_slack_dictionary(self.standard_model, slack_dict, self.config.clone())
+            .start(callback)
+            .with_preceding_work(
+                *barrier_solution.iterations(),
+                *barrier_solution.solve_time(),
+            )
+    }
+
+    /// Dictionary variables whose barrier value exceeds their dual slack, largest first.
+    fn basis_candidates(
+        &self,
+        slack_dict: &SlackDictionary,
+        barrier_solution: &SolverSolution<StandardVariableKey, StandardConstraintKey>,
+    ) -> Vec<DictionaryVariableKey> {
+        let variables = slack_dict.mapping().iter().map(|(std_var, dict_var)| {
+            (
+                *dict_var,
+                barrier_solution.value(std_var),
+                -barrier_solution.reduced_cost(std_var),
+            )
+        });
+        let slacks = slack_dict
+            .slack_mapping()
+            .iter()
+            .map(|(std_constr, dict_var)| {
+                let constraint = &self.standard_model.constraints()[std_constr];
+                let activity = constraint
+                    .lhs()
+                    .terms
+                    .iter()
+                    .map(|(var, coefficient)| coefficient * barrier_solution.value(*var))
+                    .sum::<f64>()
+                    + constraint.lhs().constant;
+                (
+                    *dict_var,
+                    constraint.rhs() - activity,
+                    barrier_solution.dual(std_constr),
+                )
+            });
+
+        let mut candidates: Vec<_> = variables
+            .chain(slacks)
+            .filter(|(_, value, dual_slack)| value > dual_slack)
+            .collect();
+        candidates.sort_by(|(_, v1, _), (_, v2, _)| v2.total_cmp(v1));
+        candidates.into_iter().map(|(var, _, _)| var).collect()
+    }
+
+    /// Pivots the candidates into the basis, each one replacing a basic variable that was
+    /// not itself chosen before.
+    fn build_basis(slack_dict: &mut SlackDictionary, candidates: Vec<DictionaryVariableKey>) {
+        let mut fixed_rows: HashSet<DictionaryRowKey> = HashSet::new();
+
+        for candidate in candidates {
+            let basic_row = slack_dict
+                .rows()
+                .iter()
+                .find(|(_, row)| row.basic_var() == candidate)
+                .map(|(row_key, _)| row_key);
+            if let Some(row_key) = basic_row {
+                fixed_rows.insert(row_key);
+                continue;
+            }
+
+            // Largest pivot for numerical stability
+            let pivot_row = slack_dict
+                .rows()
+                .iter()
+                .filter(|(row_key, _)| !fixed_rows.contains(row_key))
+                .map(|(row_key, row)| (row_key, row.non_basic_coefficient(&candidate).abs()))
+                .filter(|(_, coefficient)| *coefficient > Self::PIVOT_TOLERANCE)
+                .max_by(|(_, c1), (_, c2)| c1.total_cmp(c2))
+                .map(|(row_key, _)| row_key);
+
+            if let Some(row_key) = pivot_row {
+                slack_dict.pivot(candidate, row_key);
+                fixed_rows.insert(row_key);
+            }
+        }
+    }
+}
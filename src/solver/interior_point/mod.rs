@@ -0,0 +1,3 @@
+pub mod crossover;
+pub mod normal_equations;
+pub mod solver;
@@ -0,0 +1,271 @@
+use std::mem;
+
+/// The normal equations matrix `M = A * D * A^T` of an interior-point iteration,
+/// factorized with a sparse Cholesky decomposition `P M P^T = L L^T`.
+///
+/// Only the scaling `D` changes between iterations, so the fill-reducing ordering `P` and
+/// the sparsity pattern of `L` are computed once, by [`new`](Self::new), and every
+/// [`factorize`](Self::factorize) only recomputes the values of `L`.
+pub struct NormalEquations {
+    /// `order[p]` is the row of `A` eliminated at position `p`.
+    order: Vec<usize>,
+    /// Columns of `A` as `(position, value)` entries, sorted by position.
+    columns: Vec<Vec<(usize, f64)>>,
+    /// For every position, the columns of `A` with an entry in its row, and the index of
+    /// that entry in the column.
+    rows: Vec<Vec<(usize, usize)>>,
+    /// Start of every column of `L` in `row_indices` and `values`, followed by their length.
+    column_starts: Vec<usize>,
+    /// Row positions of the entries of `L`, column by column, each starting with its diagonal.
+    row_indices: Vec<usize>,
+    values: Vec<f64>,
+    /// For every position `j`, the columns `k < j` of `L` with an entry in row `j`, and the
+    /// index of that entry in `values`.
+    row_entries: Vec<Vec<(usize, usize)>>,
+}
+
+impl NormalEquations {
+    /// Relative size below which a Cholesky pivot is considered zero.
+    const PIVOT_TOLERANCE: f64 = 1e-30;
+    /// Replacement for zero pivots. It effectively removes the dependent row from the system.
+    const HUGE_PIVOT: f64 = 1e64;
+
+    /// Computes the ordering and the sparsity pattern of the factor of `A * D * A^T`, where
+    /// `A` has `size` rows and `columns[j]` holds the non-zero `(row, value)` entries of
+    /// its column `j`.
+    pub fn new(size: usize, columns: &[Vec<(usize, f64)>]) -> Self {
+        let order = minimum_degree(Self::adjacency(size, columns));
+
+        let mut position = vec![0; size];
+        for (p, &row) in order.iter().enumerate() {
+            position[row] = p;
+        }
+        let columns: Vec<Vec<(usize, f64)>> = columns
+            .iter()
+            .map(|column| {
+                let mut column: Vec<_> = column
+                    .iter()
+                    .map(|&(row, value)| (position[row], value))
+                    .collect();
+                column.sort_by_key(|&(p, _)| p);
+                column
+            })
+            .collect();
+        let mut rows = vec![Vec::new(); size];
+        for (k, column) in columns.iter().enumerate() {
+            for (index, &(p, _)) in column.iter().enumerate() {
+                rows[p].push((k, index));
+            }
+        }
+
+        // The pattern of a column of `L` is that of `M` below the diagonal, merged with the
+        // patterns of its children in the elimination tree
+        let mut patterns: Vec<Vec<usize>> = Vec::with_capacity(size);
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); size];
+        let mut marker = vec![usize::MAX; size];
+        for j in 0..size {
+            let mut pattern = Vec::new();
+            let rows_of_m = rows[j]
+                .iter()
+                .flat_map(|&(k, index)| columns[k][index + 1..].iter().map(|&(p, _)| p));
+            let rows_of_children = children[j]
+                .iter()
+                .flat_map(|&child| patterns[child].iter().copied().filter(|&p| p > j));
+            for p in rows_of_m.chain(rows_of_children) {
+                if marker[p] != j {
+                    marker[p] = j;
+                    pattern.push(p);
+                }
+            }
+            pattern.sort_unstable();
+            if let Some(&parent) = pattern.first() {
+                children[parent].push(j);
+            }
+            patterns.push(pattern);
+        }
+
+        let mut column_starts = Vec::with_capacity(size + 1);
+        let mut row_indices = Vec::new();
+        let mut row_entries = vec![Vec::new(); size];
+        for (j, pattern) in patterns.into_iter().enumerate() {
+            column_starts.push(row_indices.len());
+            row_indices.push(j);
+            for p in pattern {
+                row_entries[p].push((j, row_indices.len()));
+                row_indices.push(p);
+            }
+        }
+        column_starts.push(row_indices.len());
+        let values = vec![0.0; row_indices.len()];
+
+        Self {
+            order,
+            columns,
+            rows,
+            column_starts,
+            row_indices,
+            values,
+            row_entries,
+        }
+    }
+
+    /// Assembles and factorizes `A * diag(scaling) * A^T` for the columns given to `new`.
+    pub fn factorize(&mut self, scaling: &[f64]) {
+        let size = self.order.len();
+        let mut diagonal = vec![0.0; size];
+        for (column, &scale) in self.columns.iter().zip(scaling) {
+            for &(p, value) in column {
+                diagonal[p] += scale * value * value;
+            }
+        }
+        let max_diagonal = diagonal.iter().copied().fold(0.0_f64, f64::max);
+
+        // Left-looking factorization: column `j` of `M` is scattered into `work`, updated
+        // with the columns of `L` that have an entry in row `j`, and gathered into `L`
+        let mut work = vec![0.0; size];
+        for j in 0..size {
+            for &(k, index) in &self.rows[j] {
+                let scale = scaling[k] * self.columns[k][index].1;
+                for &(p, value) in &self.columns[k][index..] {
+                    work[p] += scale * value;
+                }
+            }
+            for &(k, entry) in &self.row_entries[j] {
+                let multiplier = self.values[entry];
+                for q in entry..self.column_starts[k + 1] {
+                    work[self.row_indices[q]] -= self.values[q] * multiplier;
+                }
+            }
+
+            let (start, end) = (self.column_starts[j], self.column_starts[j + 1]);
+            let pivot = mem::take(&mut work[j]);
+            let pivot = if pivot <= Self::PIVOT_TOLERANCE * max_diagonal.max(1.0) {
+                Self::HUGE_PIVOT
+            } else {
+                pivot.sqrt()
+            };
+            self.values[start] = pivot;
+            for q in start + 1..end {
+                self.values[q] = mem::take(&mut work[self.row_indices[q]]) / pivot;
+            }
+        }
+    }
+
+    /// Solves `M * x = rhs` using the Cholesky factor.
+    pub fn solve(&self, rhs: &[f64]) -> Vec<f64> {
+        let size = self.order.len();
+        let mut solution: Vec<f64> = self.order.iter().map(|&row| rhs[row]).collect();
+
+        // Forward substitution: L * y = P * rhs
+        for j in 0..size {
+            let (start, end) = (self.column_starts[j], self.column_starts[j + 1]);
+            let value = solution[j] / self.values[start];
+            solution[j] = value;
+            for q in start + 1..end {
+                solution[self.row_indices[q]] -= self.values[q] * value;
+            }
+        }
+
+        // Backward substitution: L^T * z = y
+        for j in (0..size).rev() {
+            let (start, end) = (self.column_starts[j], self.column_starts[j + 1]);
+            let mut value = solution[j];
+            for q in start + 1..end {
+                value -= self.values[q] * solution[self.row_indices[q]];
+            }
+            solution[j] = value / self.values[start];
+        }
+
+        // x = P^T * z
+        let mut result = vec![0.0; size];
+        for (p, &row) in self.order.iter().enumerate() {
+            result[row] = solution[p];
+        }
+        result
+    }
+
+    /// The off-diagonal sparsity pattern of `A * A^T`, as sorted neighbor lists.
+    fn adjacency(size: usize, columns: &[Vec<(usize, f64)>]) -> Vec<Vec<usize>> {
+        let mut rows = vec![Vec::new(); size];
+        for (k, column) in columns.iter().enumerate() {
+            for &(row, _) in column {
+                rows[row].push(k);
+            }
+        }
+
+        let mut marker = vec![usize::MAX; size];
+        (0..size)
+            .map(|i| {
+                let mut neighbors = Vec::new();
+                marker[i] = i;
+                for &k in &rows[i] {
+                    for &(row, _) in &columns[k] {
+                        if marker[row] != i {
+                            marker[row] = i;
+                            neighbors.push(row);
+                        }
+                    }
+                }
+                neighbors.sort_unstable();
+                neighbors
+            })
+            .collect()
+    }
+}
+
+/// Orders the nodes of a graph by repeatedly eliminating a node of minimum degree, which
+/// keeps the fill of the Cholesky factor low.
+///
+/// Once every remaining node is connected to more than a quarter of the others, the rest
+/// of the factor is nearly dense whatever the order, so the remaining nodes keep theirs.
+fn minimum_degree(mut adjacency: Vec<Vec<usize>>) -> Vec<usize> {
+    let size = adjacency.len();
+    let mut eliminated = vec![false; size];
+    let mut order = Vec::with_capacity(size);
+
+    for remaining in (1..=size).rev() {
+        let node = (0..size)
+            .filter(|&node| !eliminated[node])
+            .min_by_key(|&node| adjacency[node].len())
+            .unwrap();
+        if 4 * adjacency[node].len() > remaining {
+            order.extend((0..size).filter(|&node| !eliminated[node]));
+            break;
+        }
+        eliminated[node] = true;
+        order.push(node);
+
+        // Eliminating a node connects all of its neighbors
+        let neighbors = mem::take(&mut adjacency[node]);
+        for &neighbor in &neighbors {
+            let current = mem::take(&mut adjacency[neighbor]);
+            adjacency[neighbor] = merge(&current, &neighbors, |other| {
+                other != node && other != neighbor
+            });
+        }
+    }
+    order
+}
+
+/// Merges two sorted lists without duplicates, keeping the elements accepted by `keep`.
+fn merge(a: &[usize], b: &[usize], keep: impl Fn(usize) -> bool) -> Vec<usize> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        let next = if j == b.len() || (i < a.len() && a[i] <= b[j]) {
+            a[i]
+        } else {
+            b[j]
+        };
+        while i < a.len() && a[i] == next {
+            i += 1;
+        }
+        while j < b.len() && b[j] == next {
+            j += 1;
+        }
+        if keep(next) {
+            merged.push(next);
+        }
+    }
+    merged
+}
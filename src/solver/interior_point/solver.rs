@@ -0,0 +1,482 @@
+use crate::{
+    error::SolverError,
+    solver::{
+        callback::{CallbackAction, SolverCallback, SolverEvent, SolverPhase},
+        config::SolverConfig,
+        interior_point::normal_equations::NormalEquations,
+        solution::SolverSolution,
+        status::SolverStatus,
+    },
+    standard_form::{
+        constraint::StandardConstraintKey, model::StandardModel, variable::StandardVariableKey,
+    },
+};
+use slotmap::SecondaryMap;
+use std::time::Instant;
+
+/// A primal-dual point of the barrier problem.
+///
+/// `x` holds the standard variables followed by one slack per constraint, `y` the
+/// constraint duals and `s` the dual slacks (reduced costs) of `x`.
+#[derive(Debug, Clone)]
+struct Iterate {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    s: Vec<f64>,
+}
+
+/// A Newton direction of the primal-dual system.
+struct Direction {
+    dx: Vec<f64>,
+    dy: Vec<f64>,
+    ds: Vec<f64>,
+}
+
+/// Mehrotra predictor-corrector interior-point method.
+///
+/// The standard model `max c^T x, A x <= b, x >= 0` is solved as
+/// `min -c^T x, [A I] x = b, x >= 0` together with its dual
+/// `max b^T y, [A I]^T y + s = -c, s >= 0`.
+///
+/// Infeasibility and unboundedness are detected heuristically, not certified: the problem
+/// is reported infeasible (unbounded) once the dual (primal) iterate exceeds
+/// `DIVERGENCE_LIMIT` relative to the data, or when both steps stall.
+pub struct InteriorPointSolver {
+    variable_keys: Vec<StandardVariableKey>,
+    constraint_keys: Vec<StandardConstraintKey>,
+    /// Sparse columns of `[A I]`, as `(row, value)` pairs.
+    columns: Vec<Vec<(usize, f64)>>,
+    rhs: Vec<f64>,
+    /// Costs of the minimization problem, `(-c, 0)`.
+    costs: Vec<f64>,
+    objective_constant: f64,
+    iteration_count: u32,
+    config: SolverConfig,
+    start_time: Instant,
+}
+
+impl InteriorPointSolver {
+    /// Fraction of the distance to the boundary taken by every step.
+    const STEP_FACTOR: f64 = 0.995;
+    /// Iterate size, relative to the data, beyond which the problem is considered to have
+    /// no optimal solution. A badly scaled problem may reach it with an optimum.
+    const DIVERGENCE_LIMIT: f64 = 1e12;
+    /// Step length below which the method is considered stalled.
+    const MIN_STEP: f64 = 1e-12;
+
+    pub fn form_standard_model(
+        standard_model: &StandardModel,
+        config: SolverConfig,
+    ) -> Result<Self, SolverError> {
+        let objective = match standard_model.objective() {
+            Some(objective) => objective.expr(),
+            None => return Err(SolverError::ObjectiveMissing),
+        };
+        if standard_model.variables().is_empty() {
+            return Err(SolverError::NoVariables);
+        }
+
+        let variable_keys: Vec<_> = standard_model.variables().keys().collect();
+        let index: SecondaryMap<StandardVariableKey, usize> = variable_keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (*key, i))
+            .collect();
+
+        let mut columns = vec![Vec::new(); variable_keys.len()];
+        let mut constraint_keys = Vec::with_capacity(standard_model.constraints().len());
+        let mut rhs = Vec::with_capacity(standard_model.constraints().len());
+
+        for (row, (constr_key, constraint)) in standard_model.constraints().iter().enumerate() {
+            for (var, coefficient) in &constraint.lhs().terms {
+                columns[index[*var]].push((row, *coefficient));
+            }
+            constraint_keys.push(constr_key);
+            rhs.push(constraint.rhs() - constraint.lhs().constant);
+        }
+        // Slack columns
+        columns.extend((0..rhs.len()).map(|row| vec![(row, 1.0)]));
+
+        let mut costs = vec![0.0; columns.len()];
+        for (var, coefficient) in &objective.terms {
+            costs[index[*var]] = -coefficient;
+        }
+
+        Ok(Self {
+            variable_keys,
+            constraint_keys,
+            columns,
+            rhs,
+            costs,
+            objective_constant: objective.constant,
+            iteration_count: 0,
+            config,
+            start_time: Instant::now(),
+        })
+    }
+
+    pub fn start(
+        &mut self,
+        callback: &mut dyn SolverCallback,
+    ) -> SolverSolution<StandardVariableKey, StandardConstraintKey> {
+        self.start_time = Instant::now();
+        let solution = self.run(callback);
+
+        #[cfg(feature = "log")]
+        log::info!(
+            "Barrier finished: {} after {} iterations in {:.2?}",
+            solution.status(),
+            self.iteration_count,
+            solution.solve_time()
+        );
+
+        solution
+    }
+
+    fn run(
+        &mut self,
+        callback: &mut dyn SolverCallback,
+    ) -> SolverSolution<StandardVariableKey, StandardConstraintKey> {
+        let phase_changed = SolverEvent::PhaseChanged {
+            phase: SolverPhase::Barrier,
+            iteration: self.iteration_count,
+        };
+        if callback.on_event(&phase_changed) == CallbackAction::Terminate {
+            return self.solution_without_values(SolverStatus::Interrupted);
+        }
+
+        // The pattern of the normal equations is the same in every iteration
+        let mut normal = NormalEquations::new(self.rhs.len(), &self.columns);
+        let mut point = self.initial_point(&mut normal);
+        let tolerance = self.config.duality_gap_tolerance;
+        let rhs_norm = 1.0 + max_norm(&self.rhs);
+        let cost_norm = 1.0 + max_norm(&self.costs);
+
+        loop {
+            let primal_residual = self.primal_residual(&point);
+            let dual_residual = self.dual_residual(&point);
+            let primal_objective = dot(&self.costs, &point.x);
+            let dual_objective = dot(&self.rhs, &point.y);
+
+            let primal_error = max_norm(&primal_residual) / rhs_norm;
+            let dual_error = max_norm(&dual_residual) / cost_norm;
+            let gap = (primal_objective - dual_objective).abs() / (1.0 + primal_objective.abs());
+
+            if primal_error <= tolerance && dual_error <= tolerance && gap <= tolerance {
+                return self.optimal_solution(&point);
+            } else if max_norm(&point.x) > Self::DIVERGENCE_LIMIT * rhs_norm {
+                return self.solution_without_values(SolverStatus::Unbounded);
+            } else if max_norm(&point.y) > Self::DIVERGENCE_LIMIT * cost_norm {
+                return self.solution_without_values(SolverStatus::Infeasible);
+            } else if self.iteration_count >= self.config.max_iterations {
+                return self.solution_without_values(SolverStatus::MaxIterationsReached);
+            } else if self.is_time_limit_reached() {
+                return self.current_solution(&point, SolverStatus::TimeLimitReached);
+            } else if self.is_cancelled() {
+                return self.solution_without_values(SolverStatus::Interrupted);
+            }
+            self.iteration_count += 1;
+
+            let n = point.x.len() as f64;
+            let mu = dot(&point.x, &point.s) / n;
+            let scaling: Vec<f64> = point.x.iter().zip(&point.s).map(|(x, s)| x / s).collect();
+            normal.factorize(&scaling);
+
+            // Predictor: pure Newton (affine scaling) direction
+            let affine_complementarity: Vec<f64> =
+                point.x.iter().zip(&point.s).map(|(x, s)| -x * s).collect();
+            let affine = self.direction(
+                &normal,
+                &point,
+                &scaling,
+                &primal_residual,
+                &dual_residual,
+                &affine_complementarity,
+            );
+            let affine_primal_step = max_step(&point.x, &affine.dx).min(1.0);
+            let affine_dual_step = max_step(&point.s, &affine.ds).min(1.0);
+            let affine_mu = point
+                .x
+                .iter()
+                .zip(&affine.dx)
+                .zip(point.s.iter().zip(&affine.ds))
+                .map(|((x, dx), (s, ds))| {
+                    (x + affine_primal_step * dx) * (s + affine_dual_step * ds)
+                })
+                .sum::<f64>()
+                / n;
+            let sigma = (affine_mu / mu).powi(3);
+
+            // Corrector: centering towards sigma * mu plus the second-order term
+            let complementarity: Vec<f64> = (0..point.x.len())
+                .map(|j| sigma * mu - point.x[j] * point.s[j] - affine.dx[j] * affine.ds[j])
+                .collect();
+            let direction = self.direction(
+                &normal,
+                &point,
+                &scaling,
+                &primal_residual,
+                &dual_residual,
+                &complementarity,
+            );
+
+            let primal_step = (Self::STEP_FACTOR * max_step(&point.x, &direction.dx)).min(1.0);
+            let dual_step = (Self::STEP_FACTOR * max_step(&point.s, &direction.ds)).min(1.0);
+
+            if primal_step < Self::MIN_STEP && dual_step < Self::MIN_STEP {
+                // No progress is possible: whichever side is still infeasible tells why
+                let status = if primal_error > tolerance {
+                    SolverStatus::Infeasible
+                } else {
+                    SolverStatus::Unbounded
+                };
+                return self.solution_without_values(status);
+            }
+
+            axpy(&mut point.x, primal_step, &direction.dx);
+            axpy(&mut point.y, dual_step, &direction.dy);
+            axpy(&mut point.s, dual_step, &direction.ds);
+
+            #[cfg(feature = "log")]
+            log::debug!(
+                "{:>8} {:>16.8e} {:>12.4e} {:>12.4e} {:>12.4e} {:>10.4} {:>10.4}",
+                self.iteration_count,
+                self.objective_value(&point),
+                primal_error,
+                dual_error,
+                gap,
+                primal_step,
+                dual_step
+            );
+
+            if self.report_progress(&point, callback) == CallbackAction::Terminate {
+                return self.solution_without_values(SolverStatus::Interrupted);
+            }
+        }
+    }
+
+    /// Mehrotra's starting point: the least-squares solutions of the primal and dual
+    /// equality constraints, shifted into the positive orthant.
+    fn initial_point(&self, normal: &mut NormalEquations) -> Iterate {
+        normal.factorize(&vec![1.0; self.columns.len()]);
+
+        let x = self.transpose_product(&normal.solve(&self.rhs));
+        let y = normal.solve(&self.product(&self.costs));
+        let transposed = self.transpose_product(&y);
+        let s: Vec<f64> = self
+            .costs
+            .iter()
+            .zip(&transposed)
+            .map(|(c, t)| c - t)
+            .collect();
+
+        let shift = |v: &[f64]| (-1.5 * v.iter().copied().fold(f64::INFINITY, f64::min)).max(0.0);
+        let (x_shift, s_shift) = (shift(&x), shift(&s));
+        let mut x: Vec<f64> = x.iter().map(|value| value + x_shift).collect();
+        let mut s: Vec<f64> = s.iter().map(|value| value + s_shift).collect();
+
+        let complementarity = dot(&x, &s);
+        let x_shift = 0.5 * complementarity / s.iter().sum::<f64>();
+        let s_shift = 0.5 * complementarity / x.iter().sum::<f64>();
+
+        // Degenerate data (e.g. zero costs or right-hand sides) may leave components at zero
+        let interior = |value: f64, shift: f64| {
+            let shifted = value + shift;
+            if shifted.is_finite() && shifted > 0.0 {
+                shifted
+            } else {
+                1.0
+            }
+        };
+        x.iter_mut()
+            .for_each(|value| *value = interior(*value, x_shift));
+        s.iter_mut()
+            .for_each(|value| *value = interior(*value, s_shift));
+
+        Iterate { x, y, s }
+    }
+
+    /// Solves the Newton system for the given residuals, eliminating `dx` and `ds`
+    /// into the normal equations `A D A^T dy = r_p + A (D r_d - r_c / s)`.
+    fn direction(
+        &self,
+        normal: &NormalEquations,
+        point: &Iterate,
+        scaling: &[f64],
+        primal_residual: &[f64],
+        dual_residual: &[f64],
+        complementarity: &[f64],
+    ) -> Direction {
+        let reduced: Vec<f64> = (0..point.x.len())
+            .map(|j| scaling[j] * dual_residual[j] - complementarity[j] / point.s[j])
+            .collect();
+        let rhs: Vec<f64> = self
+            .product(&reduced)
+            .iter()
+            .zip(primal_residual)
+            .map(|(a, r)| a + r)
+            .collect();
+
+        let dy = normal.solve(&rhs);
+        let transposed = self.transpose_product(&dy);
+        let dx: Vec<f64> = (0..point.x.len())
+            .map(|j| {
+                scaling[j] * (transposed[j] - dual_residual[j]) + complementarity[j] / point.s[j]
+            })
+            .collect();
+        let ds: Vec<f64> = (0..point.x.len())
+            .map(|j| (complementarity[j] - point.s[j] * dx[j]) / point.x[j])
+            .collect();
+
+        Direction { dx, dy, ds }
+    }
+
+    /// `b - A x`
+    fn primal_residual(&self, point: &Iterate) -> Vec<f64> {
+        self.rhs
+            .iter()
+            .zip(self.product(&point.x))
+            .map(|(b, ax)| b - ax)
+            .collect()
+    }
+
+    /// `c - A^T y - s`
+    fn dual_residual(&self, point: &Iterate) -> Vec<f64> {
+        let transposed = self.transpose_product(&point.y);
+        (0..self.costs.len())
+            .map(|j| self.costs[j] - transposed[j] - point.s[j])
+            .collect()
+    }
+
+    /// `A v`
+    fn product(&self, vector: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; self.rhs.len()];
+        for (column, value) in self.columns.iter().zip(vector) {
+            for &(row, coefficient) in column {
+                result[row] += coefficient * value;
+            }
+        }
+        result
+    }
+
+    /// `A^T v`
+    fn transpose_product(&self, vector: &[f64]) -> Vec<f64> {
+        self.columns
+            .iter()
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|&(row, coefficient)| coefficient * vector[row])
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// The objective value of the standard (maximization) model at the given point.
+    fn objective_value(&self, point: &Iterate) -> f64 {
+        self.objective_constant - dot(&self.costs, &point.x)
+    }
+
+    fn optimal_solution(
+        &self,
+        point: &Iterate,
+    ) -> SolverSolution<StandardVariableKey, StandardConstraintKey> {
+        // The barrier minimizes the negated objective, so its duals change sign
+        let duals = self
+            .constraint_keys
+            .iter()
+            .zip(&point.y)
+            .map(|(key, y)| (*key, -y))
+            .collect();
+        let reduced_costs = self
+            .variable_keys
+            .iter()
+            .zip(&point.s)
+            .map(|(key, s)| (*key, -s))
+            .collect();
+
+        self.current_solution(point, SolverStatus::Optimal)
+            .with_dual_values(duals, reduced_costs)
+    }
+
+    /// Reports the current iterate, which may still violate the constraints by its primal
+    /// residual, e.g. when the time limit stops the method.
+    fn current_solution(
+        &self,
+        point: &Iterate,
+        status: SolverStatus,
+    ) -> SolverSolution<StandardVariableKey, StandardConstraintKey> {
+        let values = self
+            .variable_keys
+            .iter()
+            .zip(&point.x)
+            .map(|(key, value)| (*key, *value))
+            .collect();
+
+        SolverSolution::new(
+            status,
+            self.objective_value(point),
+            values,
+            self.iteration_count,
+            self.start_time.elapsed(),
+        )
+    }
+
+    fn solution_without_values(
+        &self,
+        status: SolverStatus,
+    ) -> SolverSolution<StandardVariableKey, StandardConstraintKey> {
+        SolverSolution::new_without_values(status, self.iteration_count, self.start_time.elapsed())
+    }
+
+    fn report_progress(
+        &self,
+        point: &Iterate,
+        callback: &mut dyn SolverCallback,
+    ) -> CallbackAction {
+        let interval = self.config.progress_interval;
+        if interval == 0 || self.iteration_count % interval != 0 {
+            return CallbackAction::Continue;
+        }
+
+        callback.on_event(&SolverEvent::Progress {
+            phase: SolverPhase::Barrier,
+            iteration: self.iteration_count,
+            objective_value: self.objective_value(point),
+            primal_infeasibility: max_norm(&self.primal_residual(point)),
+        })
+    }
+
+    fn is_time_limit_reached(&self) -> bool {
+        matches!(self.config.time_limit, Some(limit) if self.start_time.elapsed() >= limit)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.config
+            .cancellation_token
+            .as_ref()
+            .map_or(false, |token| token.is_cancelled())
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn max_norm(v: &[f64]) -> f64 {
+    v.iter().fold(0.0, |norm, value| norm.max(value.abs()))
+}
+
+/// `v += alpha * dv`
+fn axpy(v: &mut [f64], alpha: f64, dv: &[f64]) {
+    v.iter_mut().zip(dv).for_each(|(v, dv)| *v += alpha * dv);
+}
+
+/// Largest step along `dv` that keeps `v` non-negative (infinite if `dv >= 0`).
+fn max_step(v: &[f64], dv: &[f64]) -> f64 {
+    v.iter()
+        .zip(dv)
+        .filter(|(_, dv)| **dv < 0.0)
+        .map(|(v, dv)| -v / dv)
+        .fold(f64::INFINITY, f64::min)
+}
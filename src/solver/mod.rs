@@ -1,6 +1,11 @@
 pub mod branch_and_bound;
+#[cfg(feature = "exact")]
+pub mod exact;
+pub mod interior_point;
 pub mod simplex;
 
+pub mod callback;
+pub mod cancellation;
 pub mod config;
 pub mod solution;
 pub mod status;
@@ -0,0 +1,148 @@
+#[cfg(feature = "log")]
+use crate::solver::simplex::slack_dictionary::variable::DictionaryVariable;
+use crate::{
+    common::scalar::Scalar,
+    solver::{
+        callback::SolverPhase,
+        simplex::slack_dictionary::{variable::DictionaryVariableKey, SlackDictionary},
+        status::SolverStatus,
+    },
+    standard_form::model::StandardModel,
+};
+#[cfg(feature = "log")]
+use slotmap::SecondaryMap;
+use std::time::Duration;
+
+/// Statistics of a single pivot, as printed in one row of the iteration log.
+#[cfg_attr(not(feature = "log"), allow(dead_code))]
+pub struct PivotRecord {
+    pub iteration: u32,
+    pub phase: SolverPhase,
+    pub objective_value: f64,
+    pub primal_infeasibility: f64,
+    pub entering: DictionaryVariableKey,
+    pub leaving: DictionaryVariableKey,
+    pub degenerate_pivots: u32,
+}
+
+/// Writes the classic simplex iteration log through the `log` crate.
+///
+/// Model sizes, phase changes and the final summary are logged at `info` level, the
+/// per-iteration table at `debug` level. Without the `log` feature every method is a no-op.
+pub struct IterationLogger {
+    #[cfg(feature = "log")]
+    names: SecondaryMap<DictionaryVariableKey, String>,
+    #[cfg(feature = "log")]
+    model_size: String,
+}
+
+#[cfg(feature = "log")]
+impl IterationLogger {
+    pub fn new<S: Scalar>(standard_model: &StandardModel, slack_dict: &SlackDictionary<S>) -> Self {
+        let names = slack_dict
+            .variables()
+            .iter()
+            .map(|(dict_key, dict_var)| {
+                let name = match dict_var {
+                    DictionaryVariable::NonSlack(std_key) => standard_model
+                        .variables()
+                        .get(*std_key)
+                        .map(|var| var.name().to_string())
+                        .unwrap_or_else(|| dict_var.to_string()),
+                    DictionaryVariable::Slack(index) => format!("Slack_{}", index),
+                    DictionaryVariable::Auxiliary => String::from("Aux"),
+                };
+                (dict_key, name)
+            })
+            .collect();
+
+        let non_zeros = standard_model
+            .constraints()
+            .values()
+            .map(|constr| constr.lhs().terms.len())
+            .sum::<usize>();
+        let model_size = format!(
+            "{} variables, {} constraints, {} non-zeros",
+            standard_model.variables().len(),
+            standard_model.constraints().len(),
+            non_zeros
+        );
+        log::info!("Standardized model: {}", model_size);
+
+        Self { names, model_size }
+    }
+
+    pub fn phase_started(&self, phase: SolverPhase, iteration: u32) {
+        log::info!("Starting {:?} at iteration {}", phase, iteration);
+        log::debug!(
+            "{:>8} {:>9} {:>16} {:>12} {:>24} {:>24} {:>10}",
+            "Iter",
+            "Phase",
+            "Objective",
+            "Infeas",
+            "Entering",
+            "Leaving",
+            "Degenerate"
+        );
+    }
+
+    pub fn pivot(&self, record: &PivotRecord) {
+        if !log::log_enabled!(log::Level::Debug) {
+            return;
+        }
+        log::debug!(
+            "{:>8} {:>9} {:>16.8e} {:>12.4e} {:>24} {:>24} {:>10}",
+            record.iteration,
+            format!("{:?}", record.phase),
+            record.objective_value,
+            record.primal_infeasibility,
+            self.name(record.entering),
+            self.name(record.leaving),
+            record.degenerate_pivots
+        );
+    }
+
+    pub fn finished(
+        &self,
+        status: SolverStatus,
+        iterations: u32,
+        degenerate_pivots: u32,
+        solve_time: Duration,
+    ) {
+        log::info!(
+            "Simplex finished: {} after {} iterations ({} degenerate) in {:.2?}; model size: {}",
+            status,
+            iterations,
+            degenerate_pivots,
+            solve_time,
+            self.model_size
+        );
+    }
+
+    fn name(&self, var: DictionaryVariableKey) -> &str {
+        self.names.get(var).map(String::as_str).unwrap_or("?")
+    }
+}
+
+#[cfg(not(feature = "log"))]
+impl IterationLogger {
+    pub fn new<S: Scalar>(
+        _standard_model: &StandardModel,
+        _slack_dict: &SlackDictionary<S>,
+    ) -> Self {
+        Self {}
+    }
+
+    pub fn phase_started(&self, _phase: SolverPhase, _iteration: u32) {}
+
+    pub fn pivot(&self, _record: &PivotRecord) {}
+
+    pub fn finished(
+        &self,
+        _status: SolverStatus,
+        _iterations: u32,
+        _degenerate_pivots: u32,
+        _solve_time: Duration,
+    ) {
+    }
+}
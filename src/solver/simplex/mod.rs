@@ -1,2 +1,3 @@
+pub mod logger;
 pub mod slack_dictionary;
 pub mod solver;
@@ -2,26 +2,37 @@ pub mod row;
 pub mod variable;
 
 use crate::{
-    common::expression::LinearExpr,
+    common::{
+        expression::{GenericLinearExpr, LinearExpr},
+        scalar::Scalar,
+    },
     solver::simplex::slack_dictionary::{
         row::{DictionaryRow, DictionaryRowKey},
         variable::{DictionaryVariable, DictionaryVariableKey},
     },
-    standard_form::{model::StandardModel, variable::StandardVariableKey},
+    standard_form::{
+        constraint::StandardConstraintKey, model::StandardModel, variable::StandardVariableKey,
+    },
 };
 use slotmap::{DenseSlotMap, SecondaryMap};
 use std::{fmt, mem};
 
 #[derive(Debug, Clone)]
-pub struct SlackDictionary {
+pub struct SlackDictionary<S: Scalar = f64> {
     variables: DenseSlotMap<DictionaryVariableKey, DictionaryVariable>,
-    objective: LinearExpr<DictionaryVariableKey>,
-    rows: DenseSlotMap<DictionaryRowKey, DictionaryRow>,
+    objective: GenericLinearExpr<DictionaryVariableKey, S>,
+    rows: DenseSlotMap<DictionaryRowKey, DictionaryRow<S>>,
     mapping: SecondaryMap<StandardVariableKey, DictionaryVariableKey>,
+    slack_mapping: SecondaryMap<StandardConstraintKey, DictionaryVariableKey>,
+    drop_tolerance: S,
 }
 
-impl SlackDictionary {
-    pub fn from_standard_model(standard_model: &StandardModel) -> Self {
+impl<S: Scalar> SlackDictionary<S> {
+    /// Builds the initial dictionary, whose basis consists of the slack variables.
+    ///
+    /// Coefficients with a magnitude at or below `drop_tolerance` are dropped, both from
+    /// the model data and from every expression produced by later pivots.
+    pub fn from_standard_model(standard_model: &StandardModel, drop_tolerance: S) -> Self {
         let mut variables = DenseSlotMap::with_key();
         let mut mapping = SecondaryMap::new();
 
@@ -31,18 +42,24 @@ impl SlackDictionary {
         }
 
         let mut entries = DenseSlotMap::with_key();
-        for (index, constraint) in standard_model.constraints().values().enumerate() {
+        let mut slack_mapping = SecondaryMap::new();
+        for (index, (constr_key, constraint)) in standard_model.constraints().iter().enumerate() {
             let dict_key = variables.insert(DictionaryVariable::new_slack(index));
+            slack_mapping.insert(constr_key, dict_key);
             entries.insert(DictionaryRow::new(
                 dict_key,
-                Self::transform_expression(&(constraint.rhs() - constraint.lhs()), &mapping),
+                Self::transform_expression(
+                    &(constraint.rhs() - constraint.lhs()),
+                    &mapping,
+                    drop_tolerance.clone(),
+                ),
             ));
         }
 
         let objective = standard_model
             .objective()
             .as_ref()
-            .map(|obj| Self::transform_expression(obj.expr(), &mapping))
+            .map(|obj| Self::transform_expression(obj.expr(), &mapping, drop_tolerance.clone()))
             .unwrap();
 
         Self {
@@ -50,17 +67,21 @@ impl SlackDictionary {
             objective,
             rows: entries,
             mapping,
+            slack_mapping,
+            drop_tolerance,
         }
     }
 
-    pub fn set_objective(&mut self, objective: LinearExpr<DictionaryVariableKey>) {
+    pub fn set_objective(&mut self, mut objective: GenericLinearExpr<DictionaryVariableKey, S>) {
+        objective.prune(self.drop_tolerance.clone());
         self.objective = objective;
     }
 
     pub fn replace_objective(
         &mut self,
-        new_objective: LinearExpr<DictionaryVariableKey>,
-    ) -> LinearExpr<DictionaryVariableKey> {
+        mut new_objective: GenericLinearExpr<DictionaryVariableKey, S>,
+    ) -> GenericLinearExpr<DictionaryVariableKey, S> {
+        new_objective.prune(self.drop_tolerance.clone());
         mem::replace(&mut self.objective, new_objective)
     }
 
@@ -74,16 +95,26 @@ impl SlackDictionary {
         &mut self.variables
     }
 
-    pub fn objective(&self) -> &LinearExpr<DictionaryVariableKey> {
+    pub fn objective(&self) -> &GenericLinearExpr<DictionaryVariableKey, S> {
         &self.objective
     }
 
-    pub fn rows(&self) -> &DenseSlotMap<DictionaryRowKey, DictionaryRow> {
+    pub fn rows(&self) -> &DenseSlotMap<DictionaryRowKey, DictionaryRow<S>> {
         &self.rows
     }
 
-    pub fn objective_value(&self) -> f64 {
-        self.objective.constant
+    /// Maps every standard variable to its dictionary variable.
+    pub fn mapping(&self) -> &SecondaryMap<StandardVariableKey, DictionaryVariableKey> {
+        &self.mapping
+    }
+
+    /// Maps every standard constraint to its slack variable.
+    pub fn slack_mapping(&self) -> &SecondaryMap<StandardConstraintKey, DictionaryVariableKey> {
+        &self.slack_mapping
+    }
+
+    pub fn objective_value(&self) -> S {
+        self.objective.constant.clone()
     }
 
     pub fn std_values(&self) -> SecondaryMap<StandardVariableKey, f64> {
@@ -100,22 +131,45 @@ impl SlackDictionary {
                     std_var,
                     basic_to_entry
                         .get(*dict_var)
-                        .map(DictionaryRow::value)
+                        .map(|row| row.value().to_f64())
                         .unwrap_or(0.0),
                 )
             })
             .collect()
     }
 
-    pub fn add_var_to_all_entries(&mut self, var: DictionaryVariableKey, coefficient: f64) {
+    /// Returns the dual value of every standard constraint.
+    ///
+    /// At an optimal dictionary, the dual of a constraint is the negated objective
+    /// coefficient of its (non-basic) slack variable; basic slacks have a dual of zero.
+    pub fn std_duals(&self) -> SecondaryMap<StandardConstraintKey, f64> {
+        self.slack_mapping
+            .iter()
+            .map(|(std_constr, slack_var)| {
+                (std_constr, -self.objective.coefficient(slack_var).to_f64())
+            })
+            .collect()
+    }
+
+    /// Returns the reduced cost of every standard variable.
+    ///
+    /// Basic variables do not appear in the objective row, so their reduced cost is zero.
+    pub fn std_reduced_costs(&self) -> SecondaryMap<StandardVariableKey, f64> {
+        self.mapping
+            .iter()
+            .map(|(std_var, dict_var)| (std_var, self.objective.coefficient(dict_var).to_f64()))
+            .collect()
+    }
+
+    pub fn add_var_to_all_entries(&mut self, var: DictionaryVariableKey, coefficient: S) {
         for entry in self.rows.values_mut() {
-            entry.add_non_basic(var.clone(), coefficient);
+            entry.add_non_basic(var, coefficient.clone());
         }
     }
 
     pub fn remove_var_from_all_entries(&mut self, var: DictionaryVariableKey) {
         for entry in self.rows.values_mut() {
-            entry.remove_non_basic(var.clone());
+            entry.remove_non_basic(var);
         }
     }
 
@@ -127,6 +181,7 @@ impl SlackDictionary {
         // Get a mutable reference to the leaving entry in the arena and update its basis
         let leaving_entry = self.rows.get_mut(leaving_key).unwrap();
         leaving_entry.switch_to_basic(entering);
+        leaving_entry.prune(self.drop_tolerance.clone());
 
         // Clone the properties we need to avoid borrow-checker conflicts in the next loop
         let leaving_expr = leaving_entry.expr();
@@ -135,31 +190,44 @@ impl SlackDictionary {
         // Iterate over ALL entries mutably to substitute the expression
         for entry in self.rows.values_mut() {
             // We compare basic variables to identify if it's the same row.
-            if entry.basic_var() != new_basic_var {
-                entry.replace_non_basic_with_expr(entering, &leaving_expr);
+            if entry.basic_var() != new_basic_var
+                && entry
+                    .replace_non_basic_with_expr(entering, &leaving_expr)
+                    .is_some()
+            {
+                entry.prune(self.drop_tolerance.clone());
             }
         }
 
         // Update the objective
-        self.objective
-            .replace_var_with_expr(entering, &leaving_expr);
+        if self
+            .objective
+            .replace_var_with_expr(entering, &leaving_expr)
+            .is_some()
+        {
+            self.objective.prune(self.drop_tolerance.clone());
+        }
     }
 
     fn transform_expression(
         expression: &LinearExpr<StandardVariableKey>,
         variable_map: &SecondaryMap<StandardVariableKey, DictionaryVariableKey>,
-    ) -> LinearExpr<DictionaryVariableKey> {
+        drop_tolerance: S,
+    ) -> GenericLinearExpr<DictionaryVariableKey, S> {
         let std_terms = expression
             .terms
             .iter()
-            .map(|(var, coefficient)| (variable_map.get(*var).unwrap().clone(), *coefficient))
-            .collect::<Vec<(DictionaryVariableKey, f64)>>();
+            .map(|(var, coefficient)| (*variable_map.get(*var).unwrap(), S::from_f64(*coefficient)))
+            .collect::<Vec<(DictionaryVariableKey, S)>>();
 
-        LinearExpr::with_terms_and_constant(std_terms, expression.constant)
+        let mut expr =
+            GenericLinearExpr::with_terms_and_constant(std_terms, S::from_f64(expression.constant));
+        expr.prune(drop_tolerance);
+        expr
     }
 }
 
-impl fmt::Display for SlackDictionary {
+impl<S: Scalar> fmt::Display for SlackDictionary<S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Display the objective
         writeln!(f, "Objective = {}", self.objective)?;
@@ -1,5 +1,5 @@
 use crate::{
-    common::expression::LinearExpr,
+    common::{expression::GenericLinearExpr, scalar::Scalar},
     solver::simplex::slack_dictionary::variable::DictionaryVariableKey,
 };
 use slotmap::new_key_type;
@@ -16,16 +16,16 @@ impl fmt::Display for DictionaryRowKey {
 }
 
 #[derive(Debug, Clone)]
-pub struct DictionaryRow {
+pub struct DictionaryRow<S: Scalar = f64> {
     basic_var: DictionaryVariableKey,
-    non_basics_expr: LinearExpr<DictionaryVariableKey>,
+    non_basics_expr: GenericLinearExpr<DictionaryVariableKey, S>,
 }
 
-impl DictionaryRow {
+impl<S: Scalar> DictionaryRow<S> {
     /// Creates a new reference to a dictionary entry.
     pub fn new(
         basic_var: DictionaryVariableKey,
-        non_basics_expr: LinearExpr<DictionaryVariableKey>,
+        non_basics_expr: GenericLinearExpr<DictionaryVariableKey, S>,
     ) -> Self {
         DictionaryRow {
             basic_var,
@@ -34,18 +34,18 @@ impl DictionaryRow {
     }
 
     /// Adds a non-basic variable with a given coefficient to the expression.
-    pub fn add_non_basic(&mut self, var: DictionaryVariableKey, coefficient: f64) {
+    pub fn add_non_basic(&mut self, var: DictionaryVariableKey, coefficient: S) {
         self.non_basics_expr.add_term(var, coefficient);
     }
 
     /// Removes a non-basic variable from the expression and
     /// returns its coefficient if it existed.
-    pub fn remove_non_basic(&mut self, var: DictionaryVariableKey) -> Option<f64> {
+    pub fn remove_non_basic(&mut self, var: DictionaryVariableKey) -> Option<S> {
         self.non_basics_expr.remove_term(&var)
     }
 
     /// Retrieves the coefficient of a non-basic variable from the non-basic expression.
-    pub fn non_basic_coefficient(&self, var: &DictionaryVariableKey) -> f64 {
+    pub fn non_basic_coefficient(&self, var: &DictionaryVariableKey) -> S {
         self.non_basics_expr.coefficient(var)
     }
 
@@ -54,20 +54,25 @@ impl DictionaryRow {
     pub fn replace_non_basic_with_expr(
         &mut self,
         var: DictionaryVariableKey,
-        replacement_expr: &LinearExpr<DictionaryVariableKey>,
-    ) -> Option<f64> {
+        replacement_expr: &GenericLinearExpr<DictionaryVariableKey, S>,
+    ) -> Option<S> {
         self.non_basics_expr
             .replace_var_with_expr(var, replacement_expr)
     }
 
+    /// Drops the coefficients with a magnitude at or below `tolerance`.
+    pub fn prune(&mut self, tolerance: S) {
+        self.non_basics_expr.prune(tolerance);
+    }
+
     /// Switches the given non-basic variable to a basic variable,
     /// scaling the expression and setting the old basic variable as non-basic.
-    pub fn switch_to_basic(&mut self, non_basic_var: DictionaryVariableKey) -> Option<f64> {
+    pub fn switch_to_basic(&mut self, non_basic_var: DictionaryVariableKey) -> Option<S> {
         if let Some(coefficient) = self.non_basics_expr.remove_term(&non_basic_var) {
             let old_basic_var = mem::replace(&mut self.basic_var, non_basic_var);
 
-            self.non_basics_expr.add_term(old_basic_var, -1.0);
-            self.non_basics_expr.scale(1.0 / -coefficient);
+            self.non_basics_expr.add_term(old_basic_var, -S::ONE);
+            self.non_basics_expr.scale(S::ONE / -coefficient.clone());
             Some(coefficient)
         } else {
             None
@@ -80,17 +85,17 @@ impl DictionaryRow {
     }
 
     /// Gets the value (constant) of the dictionary entry.
-    pub fn value(&self) -> f64 {
-        self.non_basics_expr.constant
+    pub fn value(&self) -> S {
+        self.non_basics_expr.constant.clone()
     }
 
     /// Gets the expression of non-basic variables in the dictionary entry.
-    pub fn expr(&self) -> LinearExpr<DictionaryVariableKey> {
+    pub fn expr(&self) -> GenericLinearExpr<DictionaryVariableKey, S> {
         self.non_basics_expr.clone()
     }
 }
 
-impl fmt::Display for DictionaryRow {
+impl<S: Scalar> fmt::Display for DictionaryRow<S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{} = {}", self.basic_var, self.non_basics_expr)
     }
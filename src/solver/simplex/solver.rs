@@ -1,27 +1,41 @@
 use crate::{
-    common::expression::LinearExpr,
+    common::{expression::GenericLinearExpr, scalar::Scalar},
     error::SolverError,
     solver::{
+        callback::{CallbackAction, SolverCallback, SolverEvent, SolverPhase},
         config::SolverConfig,
-        simplex::slack_dictionary::{
-            row::DictionaryRowKey,
-            variable::{DictionaryVariable, DictionaryVariableKey},
-            SlackDictionary,
+        simplex::{
+            logger::{IterationLogger, PivotRecord},
+            slack_dictionary::{
+                row::DictionaryRowKey,
+                variable::{DictionaryVariable, DictionaryVariableKey},
+                SlackDictionary,
+            },
         },
         solution::SolverSolution,
         status::SolverStatus,
     },
-    standard_form::{model::StandardModel, variable::StandardVariableKey},
+    standard_form::{
+        constraint::StandardConstraintKey, model::StandardModel, variable::StandardVariableKey,
+    },
 };
 use std::{cmp, time::Instant};
 
-pub struct SimplexSolver {
-    slack_dict: SlackDictionary,
+/// Two-phase simplex over a slack dictionary whose coefficients are of type `S`.
+pub struct SimplexSolver<S: Scalar = f64> {
+    slack_dict: SlackDictionary<S>,
     iteration_count: u32,
     config: SolverConfig,
+    primal_tolerance: S,
+    dual_tolerance: S,
+    pivot_tolerance: S,
+    start_time: Instant,
+    phase: SolverPhase,
+    degenerate_pivots: u32,
+    logger: IterationLogger,
 }
 
-impl SimplexSolver {
+impl<S: Scalar> SimplexSolver<S> {
     pub fn form_standard_model(
         standard_model: &StandardModel,
         config: SolverConfig,
@@ -32,50 +46,117 @@ impl SimplexSolver {
             return Err(SolverError::ObjectiveMissing);
         }
 
-        let slack_dict = SlackDictionary::from_standard_model(standard_model);
+        let slack_dict = SlackDictionary::from_standard_model(
+            standard_model,
+            S::tolerance(config.drop_tolerance),
+        );
+        Ok(Self::from_slack_dictionary(
+            standard_model,
+            slack_dict,
+            config,
+        ))
+    }
+
+    /// Creates a solver that starts from the basis of the given dictionary,
+    /// e.g. one built by the crossover from an interior-point solution.
+    pub fn from_slack_dictionary(
+        standard_model: &StandardModel,
+        slack_dict: SlackDictionary<S>,
+        config: SolverConfig,
+    ) -> Self {
+        let logger = IterationLogger::new(standard_model, &slack_dict);
+        let (primal_tolerance, dual_tolerance, pivot_tolerance) = config.tolerances();
 
-        Ok(Self {
+        Self {
             slack_dict,
             iteration_count: 0,
+            primal_tolerance: S::tolerance(primal_tolerance),
+            dual_tolerance: S::tolerance(dual_tolerance),
+            pivot_tolerance: S::tolerance(pivot_tolerance),
             config,
-        })
+            start_time: Instant::now(),
+            phase: SolverPhase::PhaseTwo,
+            degenerate_pivots: 0,
+            logger,
+        }
     }
 
-    pub fn start(&mut self) -> SolverSolution<StandardVariableKey> {
-        let start_time = Instant::now();
+    pub fn start(
+        &mut self,
+        callback: &mut dyn SolverCallback,
+    ) -> SolverSolution<StandardVariableKey, StandardConstraintKey> {
+        self.start_time = Instant::now();
+        let solution = self.run(callback);
+        self.logger.finished(
+            *solution.status(),
+            self.iteration_count,
+            self.degenerate_pivots,
+            *solution.solve_time(),
+        );
+        solution
+    }
+
+    fn run(
+        &mut self,
+        callback: &mut dyn SolverCallback,
+    ) -> SolverSolution<StandardVariableKey, StandardConstraintKey> {
         if self.needs_phase_one() {
             let (aux_var_key, original_objective) = self.create_auxiliary_problem();
 
-            let _phase1_status = self.solve_phase1(aux_var_key);
+            let phase1_status = self.solve_phase1(aux_var_key, callback);
+
+            // Phase1 always has an optimal solution, unless the solver was stopped
+            // early; in that case no feasible point is known yet.
+            if !phase1_status.is_optimal() {
+                return SolverSolution::new_without_values(
+                    phase1_status,
+                    self.iteration_count,
+                    self.start_time.elapsed(),
+                );
+            }
 
-            // Phase1 is guaranteed that has a optimal solution,
-            // so no check is needed
-            if self.slack_dict.objective_value().abs() < self.config.tolerance {
+            if self.slack_dict.objective_value().abs() < self.primal_tolerance {
                 self.prepare_phase_two(aux_var_key, original_objective);
             } else {
-                return SolverSolution::new_infeasible(self.iteration_count, start_time.elapsed());
+                return SolverSolution::new_infeasible(
+                    self.iteration_count,
+                    self.start_time.elapsed(),
+                );
             }
         }
-        let phase2_status = self.solve();
-        SolverSolution::new(
+        let phase2_status = self.solve_phase2(callback);
+        let solution = SolverSolution::new(
             phase2_status,
-            self.slack_dict.objective_value(),
+            self.slack_dict.objective_value().to_f64(),
             self.slack_dict.std_values(),
             self.iteration_count,
-            start_time.elapsed(),
-        )
+            self.start_time.elapsed(),
+        );
+
+        // Dual information is only meaningful at an optimal basis
+        if phase2_status.is_optimal() {
+            solution.with_dual_values(
+                self.slack_dict.std_duals(),
+                self.slack_dict.std_reduced_costs(),
+            )
+        } else {
+            solution
+        }
     }
 
     fn needs_phase_one(&self) -> bool {
         self.slack_dict
             .rows()
             .values()
-            .any(|entry| entry.value() < -self.config.tolerance)
+            .any(|entry| entry.value() < -self.primal_tolerance.clone())
     }
 
     fn create_auxiliary_problem(
         &mut self,
-    ) -> (DictionaryVariableKey, LinearExpr<DictionaryVariableKey>) {
+    ) -> (
+        DictionaryVariableKey,
+        GenericLinearExpr<DictionaryVariableKey, S>,
+    ) {
         let aux_var_key = self
             .slack_dict
             .variables_mut()
@@ -83,9 +164,9 @@ impl SimplexSolver {
 
         let original_objective = self
             .slack_dict
-            .replace_objective(LinearExpr::with_term(aux_var_key, -1.0));
+            .replace_objective(GenericLinearExpr::with_term(aux_var_key, -S::ONE));
 
-        self.slack_dict.add_var_to_all_entries(aux_var_key, 1.0);
+        self.slack_dict.add_var_to_all_entries(aux_var_key, S::ONE);
 
         (aux_var_key, original_objective)
     }
@@ -93,7 +174,7 @@ impl SimplexSolver {
     fn prepare_phase_two(
         &mut self,
         aux_var: DictionaryVariableKey,
-        mut original_objective: LinearExpr<DictionaryVariableKey>,
+        mut original_objective: GenericLinearExpr<DictionaryVariableKey, S>,
     ) {
         // 1. Check if the Auxiliary variable is still in the Basis
         // We look for an entry where the basic variable is 'Aux'
@@ -110,8 +191,8 @@ impl SimplexSolver {
                 .expr()
                 .terms
                 .iter()
-                .find(|&(_, coeff)| coeff.abs() > self.config.tolerance)
-                .map(|(var, _)| var.clone());
+                .find(|&(_, coeff)| coeff.abs() > self.pivot_tolerance)
+                .map(|(var, _)| *var);
 
             if let Some(entering) = pivot_candidate {
                 // Case A: Aux is basic, but we can pivot it out.
@@ -134,43 +215,129 @@ impl SimplexSolver {
         self.slack_dict.set_objective(original_objective);
     }
 
-    fn solve_phase1(&mut self, aux_var: DictionaryVariableKey) -> SolverStatus {
+    fn solve_phase1(
+        &mut self,
+        aux_var: DictionaryVariableKey,
+        callback: &mut dyn SolverCallback,
+    ) -> SolverStatus {
+        if self.enter_phase(SolverPhase::PhaseOne, callback) == CallbackAction::Terminate {
+            return SolverStatus::Interrupted;
+        }
+
         self.iteration_count += 1;
         let leaving = self.find_phase1_initial_leaving_variable();
 
         self.slack_dict.pivot(aux_var, leaving);
 
-        self.solve()
+        self.solve(callback)
     }
 
-    fn solve(&mut self) -> SolverStatus {
+    fn solve_phase2(&mut self, callback: &mut dyn SolverCallback) -> SolverStatus {
+        if self.enter_phase(SolverPhase::PhaseTwo, callback) == CallbackAction::Terminate {
+            return SolverStatus::Interrupted;
+        }
+
+        self.solve(callback)
+    }
+
+    fn enter_phase(
+        &mut self,
+        phase: SolverPhase,
+        callback: &mut dyn SolverCallback,
+    ) -> CallbackAction {
+        self.phase = phase;
+        self.logger.phase_started(phase, self.iteration_count);
+        callback.on_event(&SolverEvent::PhaseChanged {
+            phase,
+            iteration: self.iteration_count,
+        })
+    }
+
+    fn solve(&mut self, callback: &mut dyn SolverCallback) -> SolverStatus {
         let max_iterations = self.config.max_iterations;
         while self.iteration_count < max_iterations {
+            if self.is_time_limit_reached() {
+                return SolverStatus::TimeLimitReached;
+            } else if self.is_cancelled() {
+                return SolverStatus::Interrupted;
+            }
             self.iteration_count += 1;
             match self.find_entering_variable() {
                 None => return SolverStatus::Optimal,
                 Some(entering) => match self.find_leaving_variable(&entering) {
                     None => return SolverStatus::Unbounded,
                     Some(leaving) => {
+                        let leaving_row = &self.slack_dict.rows()[leaving];
+                        let leaving_var = leaving_row.basic_var();
+                        // A degenerate pivot leaves the point (and the objective) unchanged
+                        if leaving_row.value().abs() < self.primal_tolerance {
+                            self.degenerate_pivots += 1;
+                        }
+
                         self.slack_dict.pivot(entering, leaving);
+                        self.logger.pivot(&PivotRecord {
+                            iteration: self.iteration_count,
+                            phase: self.phase,
+                            objective_value: self.slack_dict.objective_value().to_f64(),
+                            primal_infeasibility: self.primal_infeasibility(),
+                            entering,
+                            leaving: leaving_var,
+                            degenerate_pivots: self.degenerate_pivots,
+                        });
                     }
                 },
             };
+            if self.report_progress(callback) == CallbackAction::Terminate {
+                return SolverStatus::Interrupted;
+            }
         }
         SolverStatus::MaxIterationsReached
     }
 
+    fn report_progress(&self, callback: &mut dyn SolverCallback) -> CallbackAction {
+        let interval = self.config.progress_interval;
+        if interval == 0 || self.iteration_count % interval != 0 {
+            return CallbackAction::Continue;
+        }
+
+        callback.on_event(&SolverEvent::Progress {
+            phase: self.phase,
+            iteration: self.iteration_count,
+            objective_value: self.slack_dict.objective_value().to_f64(),
+            primal_infeasibility: self.primal_infeasibility(),
+        })
+    }
+
+    fn primal_infeasibility(&self) -> f64 {
+        // In Phase 1 the objective is `-aux`, so the auxiliary variable measures infeasibility
+        match self.phase {
+            SolverPhase::PhaseOne => (-self.slack_dict.objective_value().to_f64()).max(0.0),
+            SolverPhase::PhaseTwo | SolverPhase::Barrier => 0.0,
+        }
+    }
+
+    fn is_time_limit_reached(&self) -> bool {
+        matches!(self.config.time_limit, Some(limit) if self.start_time.elapsed() >= limit)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.config
+            .cancellation_token
+            .as_ref()
+            .map_or(false, |token| token.is_cancelled())
+    }
+
     fn find_entering_variable(&self) -> Option<DictionaryVariableKey> {
         self.slack_dict
             .objective()
             .terms
             .iter()
-            .filter(|&(_, coefficient)| *coefficient > self.config.tolerance)
+            .filter(|&(_, coefficient)| *coefficient > self.dual_tolerance)
             .max_by(|(v1, c1), (v2, c2)| {
                 c1.total_cmp(c2) // Compare coefficients first
                     .then_with(|| self.compare_variables(v1, v2)) // Break ties by variable type
             })
-            .map(|(var, _)| var.clone())
+            .map(|(var, _)| *var)
     }
 
     fn find_leaving_variable(&self, entering: &DictionaryVariableKey) -> Option<DictionaryRowKey> {
@@ -179,7 +346,7 @@ impl SimplexSolver {
             .iter()
             .filter_map(|(entry_key, entry)| {
                 let coefficient = entry.non_basic_coefficient(entering);
-                if coefficient < -self.config.tolerance {
+                if coefficient < -self.pivot_tolerance.clone() {
                     Some((entry_key, entry, entry.value() / coefficient))
                 } else {
                     None
@@ -1,18 +1,32 @@
-use crate::{modeling::variable::VariableKey, solver::status::SolverStatus};
+use crate::{
+    common::expression::LinearExpr,
+    modeling::{constraint::ConstraintKey, name_index::NameIndex, variable::VariableKey},
+    solver::status::SolverStatus,
+};
 use slotmap::{Key, SecondaryMap};
 use std::{fmt, ops::Index, time};
 
 /// The result of a solved optimization model.
+///
+/// `V` is the variable key type and `C` the constraint key type of the model that was solved.
 #[derive(Debug, Clone)]
-pub struct SolverSolution<V: Key> {
+pub struct SolverSolution<V: Key, C: Key = ConstraintKey> {
     status: SolverStatus,
     objective_value: Option<f64>,
     variable_values: Option<SecondaryMap<V, f64>>,
+    dual_values: Option<SecondaryMap<C, f64>>,
+    reduced_costs: Option<SecondaryMap<V, f64>>,
+    activities: Option<SecondaryMap<C, f64>>,
+    slacks: Option<SecondaryMap<C, f64>>,
+    variable_names: Option<NameIndex<V>>,
     iterations: u32,
     solve_time: time::Duration,
 }
 
-impl<V: Key> SolverSolution<V> {
+impl<V: Key, C: Key> SolverSolution<V, C> {
+    /// Slack magnitude, relative to `1 + |activity|`, up to which a constraint is binding.
+    const BINDING_TOLERANCE: f64 = 1e-9;
+
     pub fn new(
         status: SolverStatus,
         objective_value: f64,
@@ -24,21 +38,83 @@ impl<V: Key> SolverSolution<V> {
             status,
             objective_value: Some(objective_value),
             variable_values: Some(variable_values),
+            dual_values: None,
+            reduced_costs: None,
+            activities: None,
+            slacks: None,
+            variable_names: None,
             iterations,
             solve_time,
         }
     }
 
     pub fn new_infeasible(iterations: u32, solve_time: time::Duration) -> Self {
+        Self::new_without_values(SolverStatus::Infeasible, iterations, solve_time)
+    }
+
+    /// Creates a solution that carries no point, e.g. when the solver stopped
+    /// before a feasible basis was reached.
+    pub fn new_without_values(
+        status: SolverStatus,
+        iterations: u32,
+        solve_time: time::Duration,
+    ) -> Self {
         Self {
-            status: SolverStatus::Infeasible,
+            status,
             objective_value: None,
             variable_values: None,
+            dual_values: None,
+            reduced_costs: None,
+            activities: None,
+            slacks: None,
+            variable_names: None,
             iterations,
             solve_time,
         }
     }
 
+    /// Attaches the dual values of the constraints and the reduced costs of the variables.
+    pub fn with_dual_values(
+        mut self,
+        dual_values: SecondaryMap<C, f64>,
+        reduced_costs: SecondaryMap<V, f64>,
+    ) -> Self {
+        self.dual_values = Some(dual_values);
+        self.reduced_costs = Some(reduced_costs);
+        self
+    }
+
+    /// Attaches the activity (value of the left hand side) and the slack of the constraints.
+    pub fn with_constraint_values(
+        mut self,
+        activities: SecondaryMap<C, f64>,
+        slacks: SecondaryMap<C, f64>,
+    ) -> Self {
+        self.activities = Some(activities);
+        self.slacks = Some(slacks);
+        self
+    }
+
+    /// Attaches the variable names of the solved model, for lookups by name.
+    pub(crate) fn with_variable_names(mut self, variable_names: NameIndex<V>) -> Self {
+        self.variable_names = Some(variable_names);
+        self
+    }
+
+    /// Replaces the status, e.g. when a search stops at a limit after finding this point.
+    pub(crate) fn with_status(mut self, status: SolverStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Adds the iterations and time spent by a preceding solve, e.g. the barrier
+    /// iterations that led up to a crossover.
+    pub fn with_preceding_work(mut self, iterations: u32, solve_time: time::Duration) -> Self {
+        self.iterations += iterations;
+        self.solve_time += solve_time;
+        self
+    }
+
     /// Returns the final status of the solver (e.g., Optimal, Infeasible).
     pub fn status(&self) -> &SolverStatus {
         &self.status
@@ -55,7 +131,23 @@ impl<V: Key> SolverSolution<V> {
         &self.variable_values
     }
 
-    /// Returns the number of simplex iterations performed.
+    /// Returns the dual values (shadow prices) of the constraints.
+    ///
+    /// The dual value of a constraint is the rate of change of the objective value per unit
+    /// increase of its right hand side. Only available for optimal solutions.
+    pub fn dual_values(&self) -> &Option<SecondaryMap<C, f64>> {
+        &self.dual_values
+    }
+
+    /// Returns the reduced costs of the variables.
+    ///
+    /// The reduced cost of a variable is the rate of change of the objective value per unit
+    /// increase of that variable. Only available for optimal solutions.
+    pub fn reduced_costs(&self) -> &Option<SecondaryMap<V, f64>> {
+        &self.reduced_costs
+    }
+
+    /// Returns the number of iterations performed (simplex pivots and barrier iterations).
     pub fn iterations(&self) -> &u32 {
         &self.iterations
     }
@@ -76,6 +168,94 @@ impl<V: Key> SolverSolution<V> {
             .copied()
             .unwrap_or(0.0)
     }
+
+    /// Returns the value of the variable named `name` in the solved model.
+    ///
+    /// `None` if the solution carries no point, or if no variable or more than one
+    /// variable has that name.
+    pub fn value_by_name(&self, name: &str) -> Option<f64> {
+        let var_key = self.variable_names.as_ref()?.get(name)?;
+        self.variable_values.as_ref()?.get(var_key).copied()
+    }
+
+    /// Returns the dual value of a specific constraint.
+    ///
+    /// Returns `0.0` if no dual information is available.
+    pub fn dual(&self, constr_key: C) -> f64 {
+        self.dual_values
+            .as_ref()
+            .and_then(|map| map.get(constr_key))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the activities (values of the left hand sides) of the constraints.
+    pub fn activities(&self) -> &Option<SecondaryMap<C, f64>> {
+        &self.activities
+    }
+
+    /// Returns the slacks of the constraints.
+    pub fn slacks(&self) -> &Option<SecondaryMap<C, f64>> {
+        &self.slacks
+    }
+
+    /// Returns the value of the left hand side expression of a specific constraint.
+    ///
+    /// Returns `0.0` if the solution carries no point.
+    pub fn activity(&self, constr_key: C) -> f64 {
+        self.activities
+            .as_ref()
+            .and_then(|map| map.get(constr_key))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Returns how far a specific constraint is from binding: `rhs - lhs` for `<=`,
+    /// `lhs - rhs` for `>=` and `rhs - lhs` for `=` constraints.
+    ///
+    /// The slack is negative when the constraint is violated. Returns `0.0` if the
+    /// solution carries no point.
+    pub fn slack(&self, constr_key: C) -> f64 {
+        self.slacks
+            .as_ref()
+            .and_then(|map| map.get(constr_key))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Whether a specific constraint holds with equality at the solution, i.e. its slack is
+    /// zero up to a relative tolerance of `1e-9`.
+    ///
+    /// Returns `false` if the solution carries no point.
+    pub fn is_binding(&self, constr_key: C) -> bool {
+        match self.slacks.as_ref().and_then(|map| map.get(constr_key)) {
+            Some(slack) => {
+                slack.abs() <= Self::BINDING_TOLERANCE * (1.0 + self.activity(constr_key).abs())
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the reduced cost of a specific variable.
+    ///
+    /// Returns `0.0` if no dual information is available.
+    pub fn reduced_cost(&self, var_key: V) -> f64 {
+        self.reduced_costs
+            .as_ref()
+            .and_then(|map| map.get(var_key))
+            .copied()
+            .unwrap_or(0.0)
+    }
+}
+
+impl<C: Key> SolverSolution<VariableKey, C> {
+    /// Evaluates an expression of the solved model's variables at the solution,
+    /// e.g. the total cost of a subset of the objective terms.
+    ///
+    /// Variables without a value count as `0.0`.
+    pub fn eval(&self, expr: &LinearExpr<VariableKey>) -> f64 {
+        expr.evaluate(|var_key| self.value(var_key))
+    }
 }
 
 /// Allows indexing notation `solution[x]` to retrieve variable values.
@@ -90,7 +270,7 @@ impl Index<VariableKey> for SolverSolution<VariableKey> {
     }
 }
 
-impl<V: fmt::Display + Key> fmt::Display for SolverSolution<V> {
+impl<V: fmt::Display + Key, C: Key> fmt::Display for SolverSolution<V, C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Solver Status: {:?}", self.status)?;
         if self.status.is_optimal() {
@@ -12,6 +12,12 @@ pub enum SolverStatus {
     /// The solver reached the maximum allowed iterations without finding a definitive result.
     /// The returned solution is the best found so far (or the last valid basis).
     MaxIterationsReached,
+    /// The solver ran out of its wall-clock budget (`SolverConfig::time_limit`).
+    /// The returned solution is the best found so far, if a feasible point was reached.
+    TimeLimitReached,
+    /// The solve was aborted through a `CancellationToken`.
+    /// The returned solution is the best found so far, if a feasible point was reached.
+    Interrupted,
 }
 
 impl SolverStatus {
@@ -27,6 +33,8 @@ impl SolverStatus {
             Self::Infeasible => "Problem is infeasible",
             Self::Unbounded => "Problem is unbounded",
             Self::MaxIterationsReached => "Maximum iteration limit reached",
+            Self::TimeLimitReached => "Time limit reached",
+            Self::Interrupted => "Solve was interrupted",
         }
     }
 }
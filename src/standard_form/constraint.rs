@@ -13,7 +13,7 @@ impl fmt::Display for StandardConstraintKey {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StandardConstraint {
     name: Option<String>,
     lhs: LinearExpr<StandardVariableKey>,
@@ -2,5 +2,6 @@ pub mod constraint;
 pub mod model;
 pub mod objective;
 pub mod standardizer;
+#[allow(clippy::module_inception)]
 mod tests;
 pub mod variable;
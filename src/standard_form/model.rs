@@ -1,7 +1,13 @@
 use crate::{
-    common::expression::LinearExpr,
+    common::{expression::LinearExpr, scalar::Scalar},
     error::SolverError,
-    solver::{config::SolverConfig, simplex::solver::SimplexSolver, solution::SolverSolution},
+    solver::{
+        callback::SolverCallback,
+        config::{SolverAlgorithm, SolverConfig},
+        interior_point::{crossover::Crossover, solver::InteriorPointSolver},
+        simplex::solver::SimplexSolver,
+        solution::SolverSolution,
+    },
     standard_form::{
         constraint::{StandardConstraint, StandardConstraintKey},
         objective::StandardObjective,
@@ -9,10 +15,10 @@ use crate::{
     },
 };
 use slotmap::DenseSlotMap;
-use std::fmt;
+use std::{fmt, time::Instant};
 
 /// A model that enforces standard form constraints
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StandardModel {
     variables: DenseSlotMap<StandardVariableKey, StandardVariable>,
     constraints: DenseSlotMap<StandardConstraintKey, StandardConstraint>,
@@ -48,16 +54,45 @@ impl StandardModel {
         self.objective = Some(StandardObjective::new(expression.into()));
     }
 
-    pub fn solve(&mut self) -> Result<SolverSolution<StandardVariableKey>, SolverError> {
+    #[cfg(test)]
+    pub fn solve(
+        &mut self,
+    ) -> Result<SolverSolution<StandardVariableKey, StandardConstraintKey>, SolverError> {
+        self.solve_with_callback::<f64>(&mut crate::solver::callback::NoCallback)
+    }
+
+    /// Solves the model, running the simplex in the scalar type `S`.
+    ///
+    /// The interior-point method and its crossover always compute in `f64`.
+    pub fn solve_with_callback<S: Scalar>(
+        &mut self,
+        callback: &mut dyn SolverCallback,
+    ) -> Result<SolverSolution<StandardVariableKey, StandardConstraintKey>, SolverError> {
         if self.variables.is_empty() {
             return Err(SolverError::NoVariables);
         } else if self.objective.is_none() {
             return Err(SolverError::ObjectiveMissing);
         }
 
-        let mut solver = SimplexSolver::form_standard_model(&self, self.config)?;
-
-        let solution = solver.start();
+        let solution = match self.config.algorithm {
+            SolverAlgorithm::Simplex => {
+                SimplexSolver::<S>::form_standard_model(self, self.config.clone())?.start(callback)
+            }
+            SolverAlgorithm::InteriorPoint => {
+                let start_time = Instant::now();
+                let solution = InteriorPointSolver::form_standard_model(self, self.config.clone())?
+                    .start(callback);
+                if self.config.crossover && solution.status().is_optimal() {
+                    // The crossover continues the same solve, within what is left of its budget
+                    let config = self
+                        .config
+                        .remaining(start_time.elapsed(), *solution.iterations());
+                    Crossover::new(self, config).start(&solution, callback)
+                } else {
+                    solution
+                }
+            }
+        };
 
         Ok(solution)
     }
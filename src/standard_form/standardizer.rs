@@ -1,14 +1,14 @@
 use crate::{
     common::expression::LinearExpr,
     modeling::{
-        constraint::{Constraint, ConstraintSense},
+        constraint::{Constraint, ConstraintKey, ConstraintSense},
         model::Model,
         objective::{Objective, ObjectiveSense},
         variable::{Variable, VariableKey, VariableType},
     },
     solver::solution::SolverSolution,
     standard_form::{
-        constraint::StandardConstraint,
+        constraint::{StandardConstraint, StandardConstraintKey},
         model::StandardModel,
         variable::{StandardVariable, StandardVariableKey},
     },
@@ -30,14 +30,33 @@ enum VariableMapping {
     },
 }
 
+/// Records which standard constraints a domain constraint was compiled into.
+enum ConstraintMapping {
+    /// `lhs - rhs <= 0` kept as-is.
+    LessEqual(StandardConstraintKey),
+    /// `lhs - rhs >= 0` negated into a `<=` row.
+    GreaterEqual(StandardConstraintKey),
+    /// `lhs - rhs = 0` split into a `<=` row and a negated `>=` row.
+    Equal {
+        le_constr: StandardConstraintKey,
+        ge_constr: StandardConstraintKey,
+    },
+    /// `lhs - rhs + s = 0` with a slack `0 <= s <= upper - lower`, split like `Equal`.
+    Range {
+        le_constr: StandardConstraintKey,
+        ge_constr: StandardConstraintKey,
+    },
+}
+
 pub struct Standardizer {
     mapping: SecondaryMap<VariableKey, VariableMapping>,
+    constraint_mapping: SecondaryMap<ConstraintKey, ConstraintMapping>,
 }
 
 impl Standardizer {
     /// Compiles a user Model into a pure StandardModel and retains mapping info
     pub fn compile(model: &Model) -> (Self, StandardModel) {
-        let mut std_model = StandardModel::new().with_config(*model.config());
+        let mut std_model = StandardModel::new().with_config(model.config().clone());
         let mut mapping = SecondaryMap::new();
 
         // Step 1: Standardize variables
@@ -50,29 +69,42 @@ impl Standardizer {
             });
 
         // Step 2: Standardize constraints
-        model
+        let constraint_mapping = model
             .constraints()
-            .values()
-            .for_each(|constr| Self::standardize_constraint(constr, &mut std_model, &mapping));
+            .iter()
+            .map(|(constr_key, constr)| {
+                (
+                    constr_key,
+                    Self::standardize_constraint(constr, &mut std_model, &mapping),
+                )
+            })
+            .collect();
 
         // Step 3: standardize objective
-        model
-            .objective()
-            .map(|objective| Self::standardize_objective(objective, &mut std_model, &mapping));
+        if let Some(objective) = model.objective() {
+            Self::standardize_objective(objective, &mut std_model, &mapping)
+        }
 
-        (Self { mapping }, std_model)
+        (
+            Self {
+                mapping,
+                constraint_mapping,
+            },
+            std_model,
+        )
     }
 
     /// Lifts the StandardModel solution back to the domain VariableKeys
     pub fn reconstruct_solution(
         &self,
-        std_solution: &SolverSolution<StandardVariableKey>,
+        std_solution: &SolverSolution<StandardVariableKey, StandardConstraintKey>,
         original_model: &Model,
     ) -> SolverSolution<VariableKey> {
         let std_values = match std_solution.variable_values() {
             Some(vals) => vals,
             None => {
-                return SolverSolution::new_infeasible(
+                return SolverSolution::new_without_values(
+                    *std_solution.status(),
                     *std_solution.iterations(),
                     *std_solution.solve_time(),
                 )
@@ -108,13 +140,140 @@ impl Standardizer {
             })
             .collect::<SecondaryMap<_, _>>();
 
-        SolverSolution::new(
+        // 3. Evaluate the original constraints at the point
+        let (activities, slacks) = Self::constraint_values(&variable_values, original_model);
+
+        let solution = SolverSolution::new(
             *std_solution.status(),
             objective_value,
             variable_values,
             *std_solution.iterations(),
             *std_solution.solve_time(),
         )
+        .with_constraint_values(activities, slacks);
+
+        // 4. Map dual information back to the original constraints and variables
+        match std_solution.dual_values() {
+            Some(std_duals) => {
+                let dual_values = self.reconstruct_duals(std_duals, original_model);
+                let reduced_costs = Self::reconstruct_reduced_costs(&dual_values, original_model);
+                solution.with_dual_values(dual_values, reduced_costs)
+            }
+            None => solution,
+        }
+    }
+
+    /// Computes the activity (left hand side value) and the slack of every original constraint.
+    fn constraint_values(
+        variable_values: &SecondaryMap<VariableKey, f64>,
+        original_model: &Model,
+    ) -> (
+        SecondaryMap<ConstraintKey, f64>,
+        SecondaryMap<ConstraintKey, f64>,
+    ) {
+        let value = |var_key| variable_values.get(var_key).copied().unwrap_or(0.0);
+        let mut activities = SecondaryMap::new();
+        let mut slacks = SecondaryMap::new();
+
+        for (constr_key, constr) in original_model.constraints() {
+            let lhs = constr.lhs().evaluate(value);
+            let rhs = constr.rhs().evaluate(value);
+            let slack = match constr.sense() {
+                ConstraintSense::LessEqual | ConstraintSense::Equal => rhs - lhs,
+                ConstraintSense::GreaterEqual => lhs - rhs,
+                // Distance to the nearest of both bounds
+                ConstraintSense::Range => (rhs - lhs).min(lhs - rhs + constr.range_width()),
+            };
+            activities.insert(constr_key, lhs);
+            slacks.insert(constr_key, slack);
+        }
+        (activities, slacks)
+    }
+
+    /// Lifts the standard constraint duals back to the domain ConstraintKeys.
+    ///
+    /// Duals are expressed as the change of the original objective per unit increase of the
+    /// constraint's right hand side, so the sign of the standardized objective and of negated
+    /// rows must be undone.
+    fn reconstruct_duals(
+        &self,
+        std_duals: &SecondaryMap<StandardConstraintKey, f64>,
+        original_model: &Model,
+    ) -> SecondaryMap<ConstraintKey, f64> {
+        let objective_sign = match original_model.objective().unwrap().sense() {
+            ObjectiveSense::Maximize => 1.0,
+            ObjectiveSense::Minimize => -1.0,
+        };
+        let std_dual = |key: &StandardConstraintKey| std_duals.get(*key).copied().unwrap_or(0.0);
+
+        self.constraint_mapping
+            .iter()
+            .map(|(constr_key, constr_mapping)| {
+                let dual = match constr_mapping {
+                    ConstraintMapping::LessEqual(std_constr) => std_dual(std_constr),
+                    ConstraintMapping::GreaterEqual(std_constr) => -std_dual(std_constr),
+                    ConstraintMapping::Equal {
+                        le_constr,
+                        ge_constr,
+                    }
+                    | ConstraintMapping::Range {
+                        le_constr,
+                        ge_constr,
+                    } => std_dual(le_constr) - std_dual(ge_constr),
+                };
+                (constr_key, objective_sign * dual)
+            })
+            .collect()
+    }
+
+    /// Computes the reduced costs of the domain variables from the constraint duals:
+    /// `d_j = c_j - sum_i y_i * a_ij`, where `a_ij` is the coefficient of `x_j` in `lhs_i - rhs_i`.
+    fn reconstruct_reduced_costs(
+        dual_values: &SecondaryMap<ConstraintKey, f64>,
+        original_model: &Model,
+    ) -> SecondaryMap<VariableKey, f64> {
+        let objective = original_model.objective().unwrap().expr();
+        let mut reduced_costs = original_model
+            .variables()
+            .keys()
+            .map(|var_key| (var_key, objective.coefficient(&var_key)))
+            .collect::<SecondaryMap<_, _>>();
+
+        for (constr_key, constr) in original_model.constraints() {
+            let dual = dual_values.get(constr_key).copied().unwrap_or(0.0);
+            if dual == 0.0 {
+                continue;
+            }
+            let terms = constr.lhs().terms.iter().map(|&(var, coeff)| (var, coeff));
+            let rhs_terms = constr.rhs().terms.iter().map(|&(var, coeff)| (var, -coeff));
+            for (var_key, coefficient) in terms.chain(rhs_terms) {
+                if let Some(reduced_cost) = reduced_costs.get_mut(var_key) {
+                    *reduced_cost -= dual * coefficient;
+                }
+            }
+        }
+        reduced_costs
+    }
+
+    /// Adds rows restricting a domain variable to `[lower, upper]`, e.g. for a
+    /// branch-and-bound node. Infinite bounds add no row.
+    pub fn restrict(
+        &self,
+        var_key: VariableKey,
+        lower: f64,
+        upper: f64,
+        std_model: &mut StandardModel,
+    ) {
+        let mut std_lhs = Self::standardize_expression(&var_key.into(), &self.mapping);
+        let shift = std_lhs.constant;
+        std_lhs.constant = 0.0;
+
+        if upper < f64::INFINITY {
+            std_model.add_constraint(StandardConstraint::new(std_lhs.clone(), upper - shift));
+        }
+        if lower > f64::NEG_INFINITY {
+            std_model.add_constraint(StandardConstraint::new(-std_lhs, shift - lower));
+        }
     }
 
     // --- Private Compilation Helpers ---
@@ -136,9 +295,9 @@ impl Standardizer {
                 std_model.add_constraint(StandardConstraint::new(pos_var, upper_bound));
                 VariableMapping::Positive { pos_var, shift }
             }
-            VariableType::Integer | VariableType::Continuous => {
-                let lb = var.lower_bound();
-                let ub = var.upper_bound();
+            // Semi-continuous and semi-integer variables are relaxed to a range including 0
+            _ => {
+                let (lb, ub) = var.relaxed_bounds();
 
                 match (lb, ub) {
                     // Case 1: Lower bound is 0, create non-negative variable with optional upper bound
@@ -208,7 +367,7 @@ impl Standardizer {
         constr: &Constraint,
         std_model: &mut StandardModel,
         mapping: &SecondaryMap<VariableKey, VariableMapping>,
-    ) {
+    ) -> ConstraintMapping {
         let std_constr_name = format!("FromConstraint: {}", constr.name());
         // Move everything to LHS, constant to RHS
         let mut std_lhs =
@@ -219,25 +378,49 @@ impl Standardizer {
         match constr.sense() {
             ConstraintSense::LessEqual => {
                 // Already in correct form
-                std_model.add_constraint(
+                ConstraintMapping::LessEqual(std_model.add_constraint(
                     StandardConstraint::new(std_lhs, std_rhs).with_name(std_constr_name),
-                );
+                ))
             }
             ConstraintSense::GreaterEqual => {
                 // Multiply by -1 to convert to ≤
-                std_model.add_constraint(
+                ConstraintMapping::GreaterEqual(std_model.add_constraint(
                     StandardConstraint::new(-std_lhs, -std_rhs).with_name(std_constr_name),
-                );
+                ))
             }
             ConstraintSense::Equal => {
                 // Split into x ≤ b and -x ≤ -b
-                std_model.add_constraint(
+                let le_constr = std_model.add_constraint(
                     StandardConstraint::new(std_lhs.clone(), std_rhs)
                         .with_name(std_constr_name.clone()),
                 );
-                std_model.add_constraint(
+                let ge_constr = std_model.add_constraint(
                     StandardConstraint::new(-std_lhs, -std_rhs).with_name(std_constr_name),
                 );
+                ConstraintMapping::Equal {
+                    le_constr,
+                    ge_constr,
+                }
+            }
+            ConstraintSense::Range => {
+                // Add a bounded slack and split the resulting equality like above
+                let slack = std_model.add_variable(
+                    StandardVariable::new().with_name(format!("RangeSlack: {}", constr.name())),
+                );
+                std_model.add_constraint(StandardConstraint::new(slack, constr.range_width()));
+                std_lhs.add_term(slack, 1.0);
+
+                let le_constr = std_model.add_constraint(
+                    StandardConstraint::new(std_lhs.clone(), std_rhs)
+                        .with_name(std_constr_name.clone()),
+                );
+                let ge_constr = std_model.add_constraint(
+                    StandardConstraint::new(-std_lhs, -std_rhs).with_name(std_constr_name),
+                );
+                ConstraintMapping::Range {
+                    le_constr,
+                    ge_constr,
+                }
             }
         }
     }
@@ -266,16 +449,16 @@ impl Standardizer {
         for (var_key, coefficient) in &expression.terms {
             match mapping.get(*var_key).unwrap() {
                 VariableMapping::Split { pos_var, neg_var } => {
-                    new_expr.add_term(pos_var.clone(), *coefficient);
-                    new_expr.add_term(neg_var.clone(), -coefficient);
+                    new_expr.add_term(*pos_var, *coefficient);
+                    new_expr.add_term(*neg_var, -coefficient);
                 }
                 VariableMapping::Positive { pos_var, shift } => {
                     expr_shift += coefficient * shift;
-                    new_expr.add_term(pos_var.clone(), *coefficient);
+                    new_expr.add_term(*pos_var, *coefficient);
                 }
                 VariableMapping::Negative { neg_var, shift } => {
                     expr_shift += coefficient * shift;
-                    new_expr.add_term(neg_var.clone(), -coefficient);
+                    new_expr.add_term(*neg_var, -coefficient);
                 }
             }
         }
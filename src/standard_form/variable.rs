@@ -17,7 +17,7 @@ impl ExprVariable for StandardVariableKey {}
 impl_expr_ops!(StandardVariableKey);
 impl_expr_display!(StandardVariableKey);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct StandardVariable {
     name: Option<String>,
 }
@@ -39,12 +39,6 @@ impl StandardVariable {
     }
 }
 
-impl Default for StandardVariable {
-    fn default() -> Self {
-        Self { name: None }
-    }
-}
-
 impl fmt::Display for StandardVariable {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "StandardVariable({})", self.name())
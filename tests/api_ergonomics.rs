@@ -1,6 +1,8 @@
 mod common;
 use common::assert_approx_eq;
 use rustplex::prelude::*;
+use slotmap::KeyData;
+use std::collections::HashMap;
 
 #[test]
 fn test_integer_not_supported_error() {
@@ -46,3 +48,445 @@ fn test_incremental_solving() {
     let solution2 = model.solve().unwrap();
     assert_approx_eq(solution2.objective_value().unwrap(), 5.0);
 }
+
+#[test]
+fn test_callback_reports_phases_and_progress() {
+    let mut model = Model::new().with_config(SolverConfig {
+        progress_interval: 1,
+        ..Default::default()
+    });
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Minimize, 2.0 * x + 3.0 * y);
+    model.add_constraint(x + y).ge(10.0);
+    model.add_constraint(x).le(8.0);
+
+    let mut events = Vec::new();
+    let solution = model
+        .solve_with_callback(&mut |event: &SolverEvent| {
+            events.push(*event);
+            CallbackAction::Continue
+        })
+        .unwrap();
+    assert_approx_eq(solution.objective_value().unwrap(), 22.0);
+
+    let phases = events
+        .iter()
+        .filter_map(|event| match event {
+            SolverEvent::PhaseChanged { phase, .. } => Some(*phase),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(phases, vec![SolverPhase::PhaseOne, SolverPhase::PhaseTwo]);
+
+    // The last progress report carries the final objective in the original (minimization) sense
+    match events.last().unwrap() {
+        SolverEvent::Progress {
+            phase,
+            objective_value,
+            primal_infeasibility,
+            ..
+        } => {
+            assert_eq!(*phase, SolverPhase::PhaseTwo);
+            assert_approx_eq(*objective_value, 22.0);
+            assert_approx_eq(*primal_infeasibility, 0.0);
+        }
+        event => panic!("Expected a progress event, got {:?}", event),
+    }
+}
+
+#[test]
+fn test_callback_can_terminate_solve() {
+    let mut model = Model::new().with_config(SolverConfig {
+        progress_interval: 1,
+        ..Default::default()
+    });
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, x + y);
+    model.add_constraint(x).le(4.0);
+    model.add_constraint(y).le(4.0);
+
+    let solution = model
+        .solve_with_callback(&mut |event: &SolverEvent| match event {
+            SolverEvent::Progress { .. } => CallbackAction::Terminate,
+            _ => CallbackAction::Continue,
+        })
+        .unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Interrupted));
+    assert_eq!(*solution.iterations(), 1);
+}
+
+#[test]
+fn test_solve_with_single_precision() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().bounds(1.0..=6.0).continuous();
+
+    model.set_objective(Minimize, 2.0 * x + 3.0 * y);
+    let demand = model.add_constraint(x + y).ge(8.0);
+    model.add_constraint(x - y).le(4.0);
+
+    let double = model.solve().unwrap();
+    let single = model.solve_with_precision::<f32>().unwrap();
+
+    assert!(matches!(single.status(), SolverStatus::Optimal));
+    assert!((single.objective_value().unwrap() - double.objective_value().unwrap()).abs() < 1e-4);
+    assert!((single.value(x) - double.value(x)).abs() < 1e-4);
+    assert!((single.value(y) - double.value(y)).abs() < 1e-4);
+    assert!((single.dual(demand) - double.dual(demand)).abs() < 1e-4);
+}
+
+#[test]
+fn test_single_precision_expression() {
+    let mut model = Model::new();
+    let x = model.add_variable().continuous();
+
+    let expr: GenericLinearExpr<VariableKey, f32> = GenericLinearExpr::from(x) * 2.0f32;
+    let expr = (expr + 1.5f32 - 0.5f32) * 2.0f32;
+    assert_eq!(expr.coefficient(&x), 4.0f32);
+    assert_eq!(expr.constant, 2.0f32);
+    assert_eq!(<f32 as Scalar>::MIN_TOLERANCE, 1e-6);
+}
+
+#[test]
+fn test_expression_inference() {
+    let mut model = Model::new();
+    let x = model.add_variable().continuous();
+    let keys = (0..2)
+        .map(|_| model.add_variable().continuous())
+        .collect::<Vec<_>>();
+
+    let expr = LinearExpr::from(x) + keys[0] - keys[1] + 2.0;
+    assert_eq!(expr.coefficient(&keys[1]), -1.0);
+    assert_eq!(expr.constant, 2.0);
+}
+
+#[test]
+fn test_validate_inverted_bounds() {
+    let mut model = Model::new();
+    let x = model.add_variable().bounds(5.0..=2.0).continuous();
+    model.set_objective(Maximize, x);
+
+    let error = model.solve().unwrap_err();
+    assert!(matches!(
+        error,
+        SolverError::InvertedBounds { variable, lower, upper }
+            if variable == x && lower == 5.0 && upper == 2.0
+    ));
+}
+
+#[test]
+fn test_validate_inverted_range() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    model.set_objective(Maximize, x);
+    let range = model.add_constraint(x).between(5.0, 2.0);
+
+    let error = model.validate().unwrap_err();
+    assert!(matches!(
+        error,
+        SolverError::InvalidRange { constraint, lower, upper }
+            if constraint == range && lower == 5.0 && upper == 2.0
+    ));
+
+    // Infinite bounds leave a one-sided constraint
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    model.set_objective(Maximize, x);
+    let open = model.add_constraint(x).between(f64::NEG_INFINITY, 4.0);
+    assert_eq!(model.constraints()[open].range(), None);
+    assert_approx_eq(model.solve().unwrap()[x], 4.0);
+
+    // A range infinite on both sides is reported as such, not as an infinite coefficient
+    let free = model
+        .add_constraint(x)
+        .between(f64::NEG_INFINITY, f64::INFINITY);
+    assert!(matches!(
+        model.validate().unwrap_err(),
+        SolverError::InvalidRange { constraint, lower, upper }
+            if constraint == free && lower == f64::NEG_INFINITY && upper == f64::INFINITY
+    ));
+}
+
+#[test]
+fn test_try_add_constraint_rejects_invalid_range() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+
+    for (lower, upper) in [
+        (f64::NEG_INFINITY, f64::INFINITY),
+        (5.0, 2.0),
+        (f64::INFINITY, f64::INFINITY),
+        (0.0, f64::NAN),
+    ] {
+        let error = model
+            .try_add_constraint(x)
+            .between(lower, upper)
+            .unwrap_err();
+        assert!(matches!(error, SolverError::InvalidRangeBounds { .. }));
+    }
+    assert_eq!(model.constraints().len(), 0);
+
+    let open = model
+        .try_add_constraint(x)
+        .between(1.0, f64::INFINITY)
+        .unwrap();
+    assert_eq!(model.constraints()[open].range(), None);
+}
+
+#[test]
+fn test_validate_invalid_bound() {
+    let mut model = Model::new();
+    let x = model.add_variable().upper_bound(f64::NAN).continuous();
+    model.set_objective(Maximize, x);
+
+    assert!(matches!(
+        model.validate().unwrap_err(),
+        SolverError::InvalidBound { variable, value } if variable == x && value.is_nan()
+    ));
+}
+
+#[test]
+fn test_validate_non_finite_coefficients() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+    model.set_objective(Maximize, x + y);
+    let c = model.add_constraint(x + f64::INFINITY * y).le(10.0);
+
+    assert!(matches!(
+        model.validate().unwrap_err(),
+        SolverError::InvalidConstraintCoefficient { constraint, variable: Some(variable), value }
+            if constraint == c && variable == y && value == f64::INFINITY
+    ));
+
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    model.set_objective(Maximize, x + f64::NAN);
+    assert!(matches!(
+        model.validate().unwrap_err(),
+        SolverError::InvalidObjectiveCoefficient { variable: None, value } if value.is_nan()
+    ));
+}
+
+#[test]
+fn test_validate_foreign_variable() {
+    let mut other = Model::new();
+    other.add_variable().continuous();
+    let foreign = other.add_variable().continuous();
+
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    model.set_objective(Maximize, x);
+    let c = model.add_constraint(x + foreign).le(10.0);
+
+    assert!(matches!(
+        model.solve().unwrap_err(),
+        SolverError::UnknownConstraintVariable { constraint, variable }
+            if constraint == c && variable == foreign
+    ));
+
+    model.set_objective(Maximize, x + foreign);
+    assert!(model.validate().is_err());
+}
+
+#[test]
+fn test_reject_key_from_another_model() {
+    let mut first = Model::new();
+    let x = first.add_variable().non_negative().continuous();
+
+    // `y` occupies the same slot in `second` as `x` does in `first`
+    let mut second = Model::new();
+    let y = second.add_variable().non_negative().continuous();
+    assert_eq!(x.model(), Some(first.id()));
+    assert_ne!(first.id(), second.id());
+
+    second.set_objective(Maximize, y);
+    let c = second.add_constraint(x).le(10.0);
+    assert!(matches!(
+        second.solve().unwrap_err(),
+        SolverError::UnknownConstraintVariable { constraint, variable }
+            if constraint == c && variable.model() == Some(first.id())
+    ));
+
+    let mut third = Model::new();
+    third.add_variable().non_negative().continuous();
+    third.set_objective(Maximize, x);
+    assert!(matches!(
+        third.validate().unwrap_err(),
+        SolverError::UnknownObjectiveVariable { .. }
+    ));
+}
+
+#[test]
+fn test_try_add_constraint_and_objective() {
+    let mut first = Model::new();
+    let x = first.add_variable().non_negative().continuous();
+
+    let mut second = Model::new();
+    let y = second.add_variable().bounds(0.0..=4.0).continuous();
+
+    let error = second.try_add_constraint(y).le(x + 1.0).unwrap_err();
+    assert!(matches!(error, SolverError::UnknownVariable { variable } if variable == x));
+    assert!(second.try_add_constraint(x).between(0.0, 1.0).is_err());
+    assert!(second.constraints().is_empty());
+
+    let error = second.try_set_objective(Maximize, x + y).unwrap_err();
+    assert!(matches!(error, SolverError::UnknownObjectiveVariable { variable } if variable == x));
+    assert!(second.objective().is_none());
+
+    let c = second
+        .try_add_constraint(2.0 * y)
+        .name("cap")
+        .le(6.0)
+        .unwrap();
+    second.try_set_objective(Maximize, y).unwrap();
+    let solution = second.solve().unwrap();
+    assert_eq!(second.constraints()[c].name(), "cap");
+    assert_approx_eq(solution[y], 3.0);
+}
+
+#[test]
+fn test_keys_of_different_models_stay_apart() {
+    let mut first = Model::new();
+    let x = first.add_variable().non_negative().continuous();
+    let mut second = Model::new();
+    let y = second.add_variable().non_negative().continuous();
+
+    // Same slot, different models
+    assert_ne!(x, y);
+    let expr = LinearExpr::with_terms(vec![(x, 1.0), (y, 2.0)]);
+    assert_eq!(expr.terms.len(), 2);
+    assert_eq!(expr.coefficient(&y), 2.0);
+
+    // Keys coming out of the model carry its identity
+    assert_eq!(first.variables().keys().collect::<Vec<_>>(), vec![x]);
+    assert!(first
+        .variables()
+        .iter()
+        .all(|(key, _)| key.model() == Some(first.id())));
+    assert!(first.variables().contains_key(x));
+    assert!(first.variables().get(y).is_none());
+
+    // Keys rebuilt from a solution are the keys the user holds
+    first.add_constraint(x).le(3.0);
+    first.set_objective(Maximize, x);
+    let solution = first.solve().unwrap();
+    let (key, _) = solution
+        .variable_values()
+        .as_ref()
+        .unwrap()
+        .iter()
+        .next()
+        .unwrap();
+    assert_eq!(key, x);
+    let mut prices = HashMap::new();
+    prices.insert(x, 2.0);
+    assert_eq!(prices.get(&key), Some(&2.0));
+
+    // and they still belong to their model
+    second.set_objective(Maximize, y);
+    assert!(second.try_add_constraint(key).le(1.0).is_err());
+    let made_up = VariableKey::from(KeyData::from_ffi(1 << 32));
+    assert!(second.variables().get(made_up).is_none());
+    assert!(second.try_add_constraint(y + made_up).le(1.0).is_err());
+}
+
+#[test]
+fn test_sum_and_collect_expressions() {
+    let mut model = Model::new();
+    let costs = [3.0, 1.0, 2.0];
+    let x: Vec<_> = costs
+        .iter()
+        .map(|_| model.add_variable().bounds(0.0..=4.0).continuous())
+        .collect();
+
+    let total: LinearExpr<VariableKey> = x.iter().copied().sum();
+    assert_eq!(total.terms.len(), 3);
+
+    let weighted: LinearExpr<VariableKey> = x.iter().zip(costs).map(|(&v, c)| (v, c)).collect();
+    assert_eq!(weighted.coefficient(&x[0]), 3.0);
+
+    // Repeated variables are merged, constants are added up
+    let parts = vec![2.0 * x[1] + 1.0, x[1] - 3.0, -3.0 * x[1]];
+    let merged: LinearExpr<VariableKey> = parts.iter().sum();
+    assert!(merged.terms.is_empty());
+    assert_eq!(merged.constant, -2.0);
+    let owned: LinearExpr<VariableKey> = parts.into_iter().sum();
+    assert_eq!(owned.constant, -2.0);
+
+    let pairs: LinearExpr<VariableKey> = x.iter().map(|&v| (v, 2.0)).sum();
+    let quick = quicksum(x.iter().zip(costs).map(|(&v, c)| c * v));
+    assert_eq!(pairs.coefficient(&x[2]), 2.0);
+    assert_eq!(quick.coefficient(&x[2]), 2.0);
+
+    model.set_objective(Minimize, quick);
+    model.add_constraint(total).ge(5.0);
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.objective_value().unwrap(), 4.0 + 2.0);
+}
+
+#[test]
+fn test_lookup_by_name() {
+    let mut model = Model::new();
+    let x1 = model
+        .add_variable()
+        .name("x1")
+        .bounds(0.0..=3.0)
+        .continuous();
+    let x2 = model.add_variable().name("x2").non_negative().continuous();
+    let y = model
+        .add_variable_array(2)
+        .name("y")
+        .bounds(0.0..=1.0)
+        .continuous();
+    let _unnamed = model.add_variable().non_negative().continuous();
+
+    let cap = model.add_constraint(x1 + x2).name("cap").le(5.0);
+    let first = model.add_constraint(x2 - x1).name("dup").le(1.0);
+    let second = model.add_constraint(x1 + y[0]).name("dup").le(4.0);
+
+    assert_eq!(model.variable_by_name("x1"), Some(x1));
+    assert_eq!(model.variable_by_name("y[1]"), Some(y[1]));
+    assert_eq!(model.variable_by_name("<unnamed>"), None);
+    assert_eq!(model.constraint_by_name("cap"), Some(cap));
+
+    // Duplicates are reported and never resolved to an arbitrary key
+    assert_eq!(model.constraint_by_name("dup"), None);
+    assert_eq!(model.constraints_by_name("dup"), &[first, second]);
+    assert_eq!(model.duplicate_constraint_names(), vec!["dup"]);
+    assert!(model.duplicate_variable_names().is_empty());
+
+    model.set_objective(Maximize, x1 + 2.0 * x2 + y[0] + y[1]);
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.value_by_name("x1").unwrap(), solution[x1]);
+    assert_approx_eq(solution.value_by_name("x2").unwrap(), 3.0);
+    assert_eq!(solution.value_by_name("z"), None);
+
+    // Solutions keep the names of the model they were solved from
+    let x3 = model.add_variable().name("x1").non_negative().continuous();
+    assert_eq!(model.variables_by_name("x1"), &[x1, x3]);
+    assert_eq!(model.duplicate_variable_names(), vec!["x1"]);
+    assert!(solution.value_by_name("x1").is_some());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_tolerance_still_applies() {
+    let mut model = Model::new().with_config(SolverConfig {
+        tolerance: 1e-6,
+        ..Default::default()
+    });
+    let x = model.add_variable().non_negative().continuous();
+
+    // A reduced cost of 1e-7 is within the tolerance, so the start is optimal
+    model.set_objective(Maximize, 1e-7 * x);
+    model.add_constraint(x).le(10.0);
+
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    assert_eq!(solution.value(x), 0.0);
+}
@@ -1,6 +1,7 @@
 mod common;
 use common::assert_approx_eq;
 use rustplex::prelude::*;
+use std::time::Duration;
 
 #[test]
 fn test_infeasible_problem() {
@@ -115,3 +116,58 @@ fn test_no_constraints_optimal() {
     assert!(matches!(solution.status(), SolverStatus::Optimal));
     assert_approx_eq(solution.objective_value().unwrap(), 0.0);
 }
+
+#[test]
+fn test_time_limit_before_feasible_point() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, x);
+    // Needs Phase 1, which is interrupted before a feasible point is found
+    model.add_constraint(x).ge(5.0);
+    model.add_constraint(x).le(8.0);
+    model.set_time_limit(Duration::ZERO);
+
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::TimeLimitReached));
+    assert!(solution.objective_value().is_none());
+}
+
+#[test]
+fn test_time_limit_returns_current_point() {
+    let mut model = Model::new().with_config(SolverConfig {
+        time_limit: Some(Duration::ZERO),
+        ..Default::default()
+    });
+    let x = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, x);
+    model.add_constraint(x).le(8.0);
+
+    // The origin is feasible, so the starting basis is returned
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::TimeLimitReached));
+    assert_approx_eq(solution.objective_value().unwrap(), 0.0);
+}
+
+#[test]
+fn test_cancelled_solve_is_interrupted() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, x);
+    model.add_constraint(x).le(8.0);
+
+    let token = CancellationToken::new();
+    model.set_cancellation_token(token.clone());
+
+    // Cancelling through a clone aborts the solve before the first pivot
+    token.cancel();
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Interrupted));
+
+    token.reset();
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    assert_approx_eq(solution.objective_value().unwrap(), 8.0);
+}
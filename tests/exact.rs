@@ -0,0 +1,150 @@
+#![cfg(feature = "exact")]
+
+use rustplex::prelude::*;
+use rustplex::BigRational;
+
+fn ratio(numerator: i64, denominator: i64) -> BigRational {
+    BigRational::new(numerator.into(), denominator.into())
+}
+
+#[test]
+fn test_exact_maximization_with_duals() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, 3.0 * x + 4.0 * y);
+    let c1 = model.add_constraint(x + 2.0 * y).le(14.0);
+    let c2 = model.add_constraint(3.0 * x - y).le(0.0);
+    let c3 = model.add_constraint(x - y).le(2.0);
+
+    let solution = model.solve_exact().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    assert_eq!(solution.objective_value(), Some(&ratio(30, 1)));
+    assert_eq!(solution.value(x), ratio(2, 1));
+    assert_eq!(solution.value(y), ratio(6, 1));
+    assert_eq!(solution.dual(c1), ratio(15, 7));
+    assert_eq!(solution.dual(c2), ratio(2, 7));
+    assert_eq!(solution.dual(c3), ratio(0, 1));
+}
+
+#[test]
+fn test_exact_decimal_data() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().bounds(1.0..=2.0).continuous();
+
+    // 0.1, 0.2 and 0.3 have no exact binary floating-point representation
+    model.set_objective(Maximize, x);
+    model.add_constraint(0.1 * x + 0.2 * y).le(0.3);
+    let third = model.add_constraint(3.0 * x).le(y + 1.0);
+
+    let solution = model.solve_exact().unwrap();
+    assert_eq!(solution.value(x), ratio(5, 7));
+    assert_eq!(solution.value(y), ratio(8, 7));
+    assert_eq!(solution.dual(third), ratio(2, 7));
+}
+
+#[test]
+fn test_exact_minimization_with_mixed_rows() {
+    let mut model = Model::new();
+    let x = model.add_variable().upper_bound(8.0).continuous();
+    let y = model.add_variable().non_negative().continuous();
+    let z = model.add_variable().non_negative().continuous();
+    let w = model.add_variable().continuous();
+
+    model.set_objective(Minimize, 2.0 * x + 3.0 * y + 5.0 * z + 1.0);
+    let demand = model.add_constraint(x + y + z).ge(10.0);
+    model.add_constraint(x).ge(0.0);
+    model.add_constraint(w - x).eq(-2.0);
+
+    let solution = model.solve_exact().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    assert_eq!(solution.objective_value(), Some(&ratio(23, 1)));
+    assert_eq!(solution.value(w), ratio(6, 1));
+    assert_eq!(solution.dual(demand), ratio(3, 1));
+    assert_eq!(solution.reduced_cost(z), ratio(2, 1));
+}
+
+#[test]
+fn test_exact_range_constraint() {
+    let mut model = Model::new();
+    let x = model
+        .add_variable()
+        .upper_bound(8.0)
+        .non_negative()
+        .continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Minimize, 2.0 * x + 3.0 * y);
+    let demand = model.add_constraint(x + y).between(10.0, 20.0);
+
+    let solution = model.solve_exact().unwrap();
+    assert_eq!(solution.objective_value(), Some(&ratio(22, 1)));
+    assert_eq!(solution.dual(demand), ratio(3, 1));
+
+    model.set_objective(Maximize, 2.0 * x + 3.0 * y);
+    let solution = model.solve_exact().unwrap();
+    assert_eq!(solution.objective_value(), Some(&ratio(60, 1)));
+    assert_eq!(solution.dual(demand), ratio(3, 1));
+}
+
+#[test]
+fn test_exact_infeasible_and_unbounded() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    model.set_objective(Maximize, x);
+    model.add_constraint(x).ge(5.0);
+
+    let solution = model.solve_exact().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Unbounded));
+
+    model.add_constraint(x).le(3.0);
+    let solution = model.solve_exact().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Infeasible));
+    assert!(solution.objective_value().is_none());
+}
+
+#[test]
+fn test_exact_beale_cycling() {
+    let mut model = Model::new();
+    let x1 = model.add_variable().non_negative().continuous();
+    let x2 = model.add_variable().non_negative().continuous();
+    let x3 = model.add_variable().non_negative().continuous();
+    let x4 = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Minimize, -0.75 * x1 + 150.0 * x2 - 0.02 * x3 + 6.0 * x4);
+    model
+        .add_constraint(0.25 * x1 - 60.0 * x2 - 0.04 * x3 + 9.0 * x4)
+        .le(0.0);
+    model
+        .add_constraint(0.50 * x1 - 90.0 * x2 - 0.02 * x3 + 3.0 * x4)
+        .le(0.0);
+    model.add_constraint(x3).le(1.0);
+
+    let solution = model.solve_exact().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    assert_eq!(solution.objective_value(), Some(&ratio(-1, 20)));
+}
+
+#[test]
+fn test_rational_precision_simplex() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, 3.0 * x + 4.0 * y);
+    model.add_constraint(x + 2.0 * y).le(14.0);
+    model.add_constraint(3.0 * x - y).le(0.0);
+    model.add_constraint(x - y).le(2.0);
+
+    let solution = model.solve_with_precision::<BigRational>().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    assert_eq!(solution.objective_value(), &Some(30.0));
+    assert_eq!(solution.value(x), 2.0);
+    assert_eq!(solution.value(y), 6.0);
+
+    let expr = GenericLinearExpr::from(x) * ratio(1, 3) + ratio(1, 6);
+    assert_eq!(expr.coefficient(&x), ratio(1, 3));
+    assert_eq!(expr.constant, ratio(1, 6));
+}
@@ -0,0 +1,110 @@
+mod common;
+use common::assert_approx_eq;
+use rustplex::prelude::*;
+
+#[test]
+fn test_fixed_charge_facilities() {
+    // Two plants with a fixed opening cost and a cost per unit, serving a demand of 30
+    let mut model = Model::new();
+    let open_a = model.add_variable().name("open_a").binary();
+    let open_b = model.add_variable().name("open_b").binary();
+    let a = model.add_variable().bounds(0.0..=100.0).continuous();
+    let b = model.add_variable().bounds(0.0..=100.0).continuous();
+
+    // A closed plant ships nothing
+    let indicator = model.add_indicator(open_a, false, a).le(0.0).unwrap();
+    model.add_indicator(open_b, false, b).le(0.0).unwrap();
+    assert_eq!(model.indicator_constraints()[indicator].binary(), open_a);
+
+    model.add_constraint(a + b).ge(30.0);
+    model.set_objective(Minimize, 50.0 * open_a + a + 10.0 * open_b + 2.0 * b);
+
+    let solution = model.solve().unwrap();
+    assert_eq!(solution.status(), &SolverStatus::Optimal);
+    assert_approx_eq(solution.objective_value().unwrap(), 70.0);
+    assert_approx_eq(solution[open_a], 0.0);
+    assert_approx_eq(solution[open_b], 1.0);
+    assert_approx_eq(solution[b], 30.0);
+
+    let check = model.check_solution(&solution);
+    assert!(check.max_integrality_violation() < 1e-9);
+    assert!(check.max_primal_violation().absolute < 1e-9);
+}
+
+#[test]
+fn test_indicator_equality_and_inactive_value() {
+    let mut model = Model::new();
+    let small = model.add_variable().binary();
+    let x = model.add_variable().bounds(0.0..=20.0).continuous();
+
+    // Either exactly 5 units, or at least 8 of them
+    let exact = model
+        .add_indicator(small, true, x)
+        .name("exact")
+        .eq(5.0)
+        .unwrap();
+    let large = model
+        .add_indicator(small, false, x)
+        .name("large")
+        .ge(8.0)
+        .unwrap();
+    model.set_objective(Minimize, x);
+
+    // Both rows of the equality can be found by name
+    let rows = model.indicator_constraints()[exact].constraints().to_vec();
+    assert_eq!(model.constraint_by_name("exact[le]"), Some(rows[0]));
+    assert_eq!(model.constraint_by_name("exact[ge]"), Some(rows[1]));
+    let row = model.indicator_constraints()[large].constraints()[0];
+    assert_eq!(model.constraint_by_name("large"), Some(row));
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[small], 1.0);
+    assert_approx_eq(solution[x], 5.0);
+
+    // Forcing the larger lot
+    model.add_constraint(small).le(0.0);
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 8.0);
+}
+
+#[test]
+fn test_indicator_on_semi_continuous_variable() {
+    // The big-M of `s >= 7` must allow s = 0, below the lower bound of 5
+    let mut model = Model::new();
+    let y = model.add_variable().binary();
+    let s = model.add_variable().bounds(5.0..=10.0).semi_continuous();
+    model.add_indicator(y, true, s).ge(7.0).unwrap();
+    model.set_objective(Minimize, s);
+
+    let solution = model.solve().unwrap();
+    assert_eq!(solution.status(), &SolverStatus::Optimal);
+    assert_approx_eq(solution[s], 0.0);
+    assert_approx_eq(solution[y], 0.0);
+
+    // Switching the indicator on
+    model.add_constraint(y).ge(1.0);
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[s], 7.0);
+}
+
+#[test]
+fn test_invalid_indicator_rejected() {
+    let mut model = Model::new();
+    let flag = model.add_variable().bounds(0.0..=1.0).continuous();
+    let y = model.add_variable().binary();
+    let x = model.add_variable().non_negative().continuous();
+
+    let error = model.add_indicator(flag, true, x).le(1.0).unwrap_err();
+    assert!(
+        matches!(error, SolverError::InvalidIndicatorVariable { variable } if variable == flag)
+    );
+
+    // `x` has no upper bound, so `x <= 1` has no big-M
+    let error = model.add_indicator(y, true, x).le(1.0).unwrap_err();
+    assert!(matches!(error, SolverError::UnboundedIndicator { variable } if variable == x));
+    assert!(model.indicator_constraints().is_empty());
+    assert!(model.constraints().is_empty());
+
+    // `x >= 1` is bounded by the lower bound of `x`
+    assert!(model.add_indicator(y, true, x).ge(1.0).is_ok());
+}
@@ -0,0 +1,232 @@
+mod common;
+use common::assert_approx_eq;
+use rustplex::prelude::*;
+
+fn interior_point_model() -> Model {
+    let mut model = Model::new();
+    model.set_algorithm(SolverAlgorithm::InteriorPoint);
+    model
+}
+
+#[test]
+fn test_interior_point_maximization() {
+    let mut model = interior_point_model();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, 3.0 * x + 4.0 * y);
+    let c1 = model.add_constraint(x + 2.0 * y).le(14.0);
+    let c2 = model.add_constraint(3.0 * x - y).le(0.0);
+    let c3 = model.add_constraint(x - y).le(2.0);
+
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    assert_approx_eq(solution.objective_value().unwrap(), 30.0);
+    assert_approx_eq(solution[x], 2.0);
+    assert_approx_eq(solution[y], 6.0);
+    assert_approx_eq(solution.dual(c1), 15.0 / 7.0);
+    assert_approx_eq(solution.dual(c2), 2.0 / 7.0);
+    assert_approx_eq(solution.dual(c3), 0.0);
+}
+
+#[test]
+fn test_interior_point_minimization_with_bounds() {
+    let mut model = interior_point_model();
+    let x = model.add_variable().bounds(1.0..=8.0).continuous();
+    let y = model.add_variable().non_negative().continuous();
+    let z = model.add_variable().continuous();
+
+    model.set_objective(Minimize, 2.0 * x + 3.0 * y + 5.0);
+    let demand = model.add_constraint(x + y + z).ge(10.0);
+    model.add_constraint(z).eq(0.0);
+
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    assert_approx_eq(solution.objective_value().unwrap(), 27.0);
+    assert_approx_eq(solution[x], 8.0);
+    assert_approx_eq(solution[y], 2.0);
+    assert_approx_eq(solution.dual(demand), 3.0);
+}
+
+#[test]
+fn test_interior_point_matches_simplex() {
+    let build = |algorithm| {
+        let mut model = Model::new();
+        model.set_algorithm(algorithm);
+        let vars: Vec<_> = (0..6)
+            .map(|_| model.add_variable().bounds(0.0..=10.0).continuous())
+            .collect();
+
+        let objective = vars
+            .iter()
+            .enumerate()
+            .fold(LinearExpr::new(), |expr, (i, var)| {
+                expr + (1.0 + i as f64) * *var
+            });
+        model.set_objective(Maximize, objective);
+
+        for row in 0..4 {
+            let lhs = vars
+                .iter()
+                .enumerate()
+                .fold(LinearExpr::new(), |expr, (j, var)| {
+                    expr + (((row * 7 + j * 3) % 5) as f64 + 1.0) * *var
+                });
+            model.add_constraint(lhs).le(20.0 + 5.0 * row as f64);
+        }
+        model.solve().unwrap()
+    };
+
+    let simplex = build(SolverAlgorithm::Simplex);
+    let barrier = build(SolverAlgorithm::InteriorPoint);
+
+    assert!(matches!(barrier.status(), SolverStatus::Optimal));
+    assert_approx_eq(
+        barrier.objective_value().unwrap(),
+        simplex.objective_value().unwrap(),
+    );
+}
+
+#[test]
+fn test_interior_point_infeasible() {
+    let mut model = interior_point_model();
+    let x = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, x);
+    model.add_constraint(x).ge(5.0);
+    model.add_constraint(x).le(3.0);
+
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Infeasible));
+    assert!(solution.objective_value().is_none());
+}
+
+#[test]
+fn test_interior_point_unbounded() {
+    let mut model = interior_point_model();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, x + y);
+    model.add_constraint(x - y).le(1.0);
+
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Unbounded));
+}
+
+#[test]
+fn test_interior_point_time_limit_returns_iterate() {
+    let mut model = interior_point_model();
+    model.set_time_limit(std::time::Duration::ZERO);
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, x + y);
+    model.add_constraint(x + 2.0 * y).le(4.0);
+
+    // The barrier stops at its starting point, which is still reported
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::TimeLimitReached));
+    assert!(solution.variable_values().is_some());
+    assert_approx_eq(
+        solution.objective_value().unwrap(),
+        solution[x] + solution[y],
+    );
+}
+
+#[test]
+fn test_interior_point_reports_barrier_events() {
+    let mut model = Model::new().with_config(SolverConfig {
+        algorithm: SolverAlgorithm::InteriorPoint,
+        progress_interval: 1,
+        ..Default::default()
+    });
+    let x = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Minimize, x);
+    model.add_constraint(x).ge(2.0);
+
+    let mut events = Vec::new();
+    let solution = model
+        .solve_with_callback(&mut |event: &SolverEvent| {
+            events.push(*event);
+            CallbackAction::Continue
+        })
+        .unwrap();
+
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    assert_eq!(
+        events[0],
+        SolverEvent::PhaseChanged {
+            phase: SolverPhase::Barrier,
+            iteration: 0
+        }
+    );
+    assert!(events.iter().all(|event| match event {
+        SolverEvent::Progress {
+            phase,
+            objective_value,
+            ..
+        } => *phase == SolverPhase::Barrier && *objective_value > 0.0,
+        _ => true,
+    }));
+}
+
+#[test]
+fn test_crossover_returns_vertex() {
+    let build = |crossover| {
+        let mut model = interior_point_model();
+        model.set_crossover(crossover);
+        let x = model.add_variable().non_negative().continuous();
+        let y = model.add_variable().non_negative().continuous();
+
+        // Every point between (1, 3) and (3, 1) is optimal
+        model.set_objective(Maximize, x + y);
+        let total = model.add_constraint(x + y).le(4.0);
+        model.add_constraint(x).le(3.0);
+        model.add_constraint(y).le(3.0);
+
+        let solution = model.solve().unwrap();
+        (solution, x, y, total)
+    };
+
+    // The barrier converges to the center of the optimal face
+    let (interior, x, y, _) = build(false);
+    assert_approx_eq(interior.objective_value().unwrap(), 4.0);
+    assert_approx_eq(interior[x], 2.0);
+    assert_approx_eq(interior[y], 2.0);
+
+    let (vertex, x, y, total) = build(true);
+    assert!(matches!(vertex.status(), SolverStatus::Optimal));
+    assert_approx_eq(vertex.objective_value().unwrap(), 4.0);
+    assert_approx_eq(vertex[x].min(vertex[y]), 1.0);
+    assert_approx_eq(vertex[x].max(vertex[y]), 3.0);
+    assert_approx_eq(vertex.dual(total), 1.0);
+    assert!(vertex.iterations() > interior.iterations());
+}
+
+#[test]
+fn test_crossover_shares_iteration_budget() {
+    let build = |crossover, max_iterations| {
+        let mut model = interior_point_model();
+        model.set_crossover(crossover);
+        model.set_max_iterations(max_iterations);
+        let x = model.add_variable().non_negative().continuous();
+        let y = model.add_variable().non_negative().continuous();
+
+        model.set_objective(Maximize, x + y);
+        model.add_constraint(x + y).le(4.0);
+        model.add_constraint(x).le(3.0);
+        model.add_constraint(y).le(3.0);
+        model.solve().unwrap()
+    };
+
+    // The crossover only gets the iterations the barrier left over
+    let barrier_iterations = *build(false, 10_000).iterations();
+    let solution = build(true, barrier_iterations);
+    assert!(matches!(
+        solution.status(),
+        SolverStatus::MaxIterationsReached
+    ));
+    assert_eq!(*solution.iterations(), barrier_iterations);
+}
@@ -49,3 +49,54 @@ fn test_scale_hypercube_100_vars() {
     assert!(matches!(solution.status(), SolverStatus::Optimal));
     assert_approx_eq(solution.objective_value().unwrap(), 100.0);
 }
+
+/// Times both algorithms on a dense LP (`cargo test --release -- --nocapture` to compare).
+fn solve_dense(n: usize, algorithm: SolverAlgorithm, crossover: bool) -> f64 {
+    let mut model = Model::new();
+    model.set_algorithm(algorithm);
+    model.set_crossover(crossover);
+    let x: Vec<_> = (0..n)
+        .map(|_| model.add_variable().non_negative().continuous())
+        .collect();
+
+    // Deterministic pseudo-random coefficients in [0.1, 10.1)
+    let mut state = 12345_u64;
+    let mut random = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 33) % 1000) as f64 / 100.0 + 0.1
+    };
+    for _ in 0..n {
+        let row: LinearExpr<_> = x.iter().map(|&var| (var, random())).collect();
+        model.add_constraint(row).le(100.0 + random());
+    }
+    let objective: LinearExpr<_> = x.iter().map(|&var| (var, random())).collect();
+    model.set_objective(Maximize, objective);
+
+    let start = Instant::now();
+    let solution = model.solve().unwrap();
+    let duration = start.elapsed();
+
+    println!(
+        "Solved dense {}x{} LP with {:?} (crossover: {}) in {} iterations, {:?}",
+        n,
+        n,
+        algorithm,
+        crossover,
+        solution.iterations(),
+        duration
+    );
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    solution.objective_value().unwrap()
+}
+
+#[test]
+fn test_scale_dense_interior_point_vs_simplex() {
+    let simplex = solve_dense(150, SolverAlgorithm::Simplex, false);
+    let barrier = solve_dense(150, SolverAlgorithm::InteriorPoint, false);
+    let crossover = solve_dense(150, SolverAlgorithm::InteriorPoint, true);
+
+    assert!((simplex - barrier).abs() <= 1e-6 * simplex.abs());
+    assert_approx_eq(simplex, crossover);
+}
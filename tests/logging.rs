@@ -0,0 +1,59 @@
+#![cfg(feature = "log")]
+
+use log::{Level, LevelFilter, Metadata, Record};
+use rustplex::prelude::*;
+use std::sync::Mutex;
+
+/// Collects every log line so the iteration log can be inspected.
+struct CapturingLogger {
+    lines: Mutex<Vec<String>>,
+}
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Debug
+    }
+
+    fn log(&self, record: &Record) {
+        self.lines
+            .lock()
+            .unwrap()
+            .push(format!("{}", record.args()));
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn test_iteration_log() {
+    let logger: &'static CapturingLogger = Box::leak(Box::new(CapturingLogger {
+        lines: Mutex::new(Vec::new()),
+    }));
+    log::set_logger(logger).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+
+    let mut model = Model::new();
+    let x = model.add_variable().name("x").non_negative().continuous();
+    let y = model.add_variable().name("y").non_negative().continuous();
+
+    model.set_objective(Minimize, 2.0 * x + 3.0 * y);
+    model.add_constraint(x + y).ge(10.0);
+    model.add_constraint(x).le(8.0);
+    model.solve().unwrap();
+
+    let lines = logger.lines.lock().unwrap();
+    assert!(lines
+        .iter()
+        .any(|line| line == "Standardized model: 2 variables, 2 constraints, 3 non-zeros"));
+    assert!(lines.iter().any(|line| line.contains("Starting PhaseOne")));
+    assert!(lines.iter().any(|line| line.contains("Starting PhaseTwo")));
+    assert!(lines
+        .iter()
+        .any(|line| line.contains("Entering") && line.contains("Degenerate")));
+    assert!(lines
+        .iter()
+        .any(|line| line.contains("FromVariable: x⁺") && line.contains("PhaseTwo")));
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("Simplex finished: Optimal solution found")));
+}
@@ -0,0 +1,173 @@
+mod common;
+use common::assert_approx_eq;
+use rustplex::prelude::*;
+use rustplex::ConstraintSense;
+
+#[test]
+fn test_lp_macro_builds_model() {
+    let mut model = Model::new();
+    let x = model.add_variable().name("x").non_negative().continuous();
+    let y = model.add_variable().name("y").non_negative().continuous();
+
+    lp!(model, maximize 3*x + 2*y);
+    let cap = lp!(model, x + y <= 4, "cap");
+    let mix = lp!(model, x + 3 * y <= 6);
+    let floor = lp!(model, -x + y >= -3);
+
+    assert_eq!(model.constraints()[cap].name(), "cap");
+    assert!(matches!(
+        model.constraints()[cap].sense(),
+        ConstraintSense::LessEqual
+    ));
+    assert_eq!(model.constraints()[mix].lhs().coefficient(&y), 3.0);
+    assert_eq!(model.constraints()[floor].lhs().coefficient(&x), -1.0);
+    assert_eq!(model.constraints()[floor].rhs().constant, -3.0);
+
+    let solution = model.solve().unwrap();
+    assert_eq!(solution.status(), &SolverStatus::Optimal);
+    assert_approx_eq(solution.objective_value().unwrap(), 11.5);
+    assert_approx_eq(solution[x], 3.5);
+    assert_approx_eq(solution[y], 0.5);
+}
+
+#[test]
+fn test_constraint_macro_with_sums() {
+    let cost = [4.0, 3.0, 5.0];
+    let weight = [2.0, 1.0, 3.0];
+
+    let mut model = Model::new();
+    let x = model
+        .add_variable_array(3)
+        .name("x")
+        .bounds(0.0..=4.0)
+        .continuous();
+
+    lp!(model, minimize sum(i in 0..3) cost[i]*x[i] + 1);
+    constraint!(model, sum(i in 0..3) weight[i]*x[i] >= 2*(3 + 1), "demand");
+    let balance = constraint!(model, x[0] - x[2] == sum(i in 1..3) 0.5*x[i]);
+
+    let lhs = model.constraints()[balance].lhs();
+    let rhs = model.constraints()[balance].rhs();
+    assert_eq!(lhs.coefficient(&x[2]), -1.0);
+    assert_eq!(rhs.coefficient(&x[1]), 0.5);
+
+    let solution = model.solve().unwrap();
+    assert_eq!(solution.status(), &SolverStatus::Optimal);
+    assert_approx_eq(
+        solution[x[0]] - solution[x[2]],
+        0.5 * (solution[x[1]] + solution[x[2]]),
+    );
+    assert_approx_eq(
+        solution.objective_value().unwrap(),
+        1.0 + solution.eval(&(4.0 * x[0] + 3.0 * x[1] + 5.0 * x[2])),
+    );
+    assert!(2.0 * solution[x[0]] + solution[x[1]] + 3.0 * solution[x[2]] >= 8.0 - 1e-9);
+}
+
+#[test]
+fn test_nested_sums_and_parenthesized_bodies() {
+    let mut model = Model::new();
+    let x = model.add_variable_array((2, 2)).non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    let rows = lp!(model, sum(i in 0..2) sum(j in 0..2) x[(i, j)] - y <= 10);
+    let diff = lp!(model, sum(i in 0..2) (x[(i, 0)] - x[(i, 1)]) >= 0);
+
+    let rows = model.constraints()[rows].lhs();
+    assert_eq!(rows.terms.len(), 5);
+    assert_eq!(rows.coefficient(&y), -1.0);
+    let diff = model.constraints()[diff].lhs();
+    assert_eq!(diff.coefficient(&x[(1, 0)]), 1.0);
+    assert_eq!(diff.coefficient(&x[(1, 1)]), -1.0);
+}
+
+#[test]
+fn test_many_written_out_terms() {
+    let mut model = Model::new();
+    let x = model.add_variable_array(16).bounds(0.0..=1.0).continuous();
+
+    lp!(model, maximize 16*x[0] + 15*x[1] + 14*x[2] + 13*x[3] + 12*x[4] + 11*x[5]
+        + 10*x[6] + 9*x[7] + 8*x[8] + 7*x[9] + 6*x[10] + 5*x[11] + 4*x[12] + 3*x[13]
+        + 2*x[14] + 1*x[15]);
+    let budget = lp!(
+        model,
+        1 * x[0]
+            + 1 * x[1]
+            + 1 * x[2]
+            + 1 * x[3]
+            + 1 * x[4]
+            + 1 * x[5]
+            + 1 * x[6]
+            + 1 * x[7]
+            + 1 * x[8]
+            + 1 * x[9]
+            + 1 * x[10]
+            + 1 * x[11]
+            + 1 * x[12]
+            + 1 * x[13]
+            + 1 * x[14]
+            + 1 * x[15]
+            <= 2.5,
+        "budget"
+    );
+    lp!(
+        model,
+        x[0] - 0.5 * x[1] + x[2] - 0.5 * x[3] + x[4] - 0.5 * x[5] + x[6] - 0.5 * x[7] + x[8]
+            - 0.5 * x[9]
+            + x[10]
+            - 0.5 * x[11]
+            + x[12]
+            - 0.5 * x[13]
+            + x[14]
+            - 0.5 * x[15]
+            >= 0.25 - 0.25
+    );
+
+    assert_eq!(model.constraints()[budget].lhs().terms.len(), 16);
+    assert_eq!(model.constraints()[budget].name(), "budget");
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(
+        solution.objective_value().unwrap(),
+        16.0 + 15.0 + 0.5 * 14.0,
+    );
+    assert_approx_eq(solution[x[2]], 0.5);
+}
+
+#[test]
+fn test_tuple_fields_are_not_coefficients() {
+    let mut model = Model::new();
+    let pair = (
+        model.add_variable().non_negative().continuous(),
+        model.add_variable().non_negative().continuous(),
+    );
+    let weights = (2.0, 3.0);
+
+    let row = lp!(
+        model,
+        weights.0 * pair.0 + 4 * pair.1 - weights.1 * pair.1 <= 10
+    );
+
+    let lhs = model.constraints()[row].lhs();
+    assert_eq!(lhs.coefficient(&pair.0), 2.0);
+    assert_eq!(lhs.coefficient(&pair.1), 1.0);
+}
+
+#[test]
+fn test_double_negation() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    let row = lp!(model, --x - -2 * y >= --1);
+    let other = lp!(model, x + - -y - +x <= -(-3));
+
+    let row = &model.constraints()[row];
+    assert_eq!(row.lhs().coefficient(&x), 1.0);
+    assert_eq!(row.lhs().coefficient(&y), 2.0);
+    assert_eq!(row.rhs().constant, 1.0);
+    let other = &model.constraints()[other];
+    assert_eq!(other.lhs().coefficient(&x), 0.0);
+    assert_eq!(other.lhs().coefficient(&y), 1.0);
+    assert_eq!(other.rhs().constant, 3.0);
+}
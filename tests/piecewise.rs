@@ -0,0 +1,219 @@
+mod common;
+use common::assert_approx_eq;
+use rustplex::prelude::*;
+
+#[test]
+fn test_convex_tariff_minimized() {
+    // Tiered tariff: 1 per unit up to 10, 2 per unit up to 20, 4 per unit above
+    let tariff = [(0.0, 0.0), (10.0, 10.0), (20.0, 30.0), (40.0, 110.0)];
+
+    let mut model = Model::new();
+    let a = model.add_variable().name("a").continuous();
+    let b = model.add_variable().name("b").continuous();
+    let cost_a = model.add_piecewise_linear(a, &tariff).unwrap();
+    let cost_b = model.add_piecewise_linear(b, &tariff).unwrap();
+    assert_eq!(model.variable_by_name("pwl(a)"), Some(cost_a));
+
+    model.add_constraint(a + b).ge(35.0);
+    model.set_objective(Minimize, cost_a + cost_b);
+
+    let solution = model.solve().unwrap();
+    // Both plants stay in the second tier
+    assert_approx_eq(solution.objective_value().unwrap(), 50.0);
+    assert_approx_eq(solution[cost_a] + solution[cost_b], 50.0);
+    assert_approx_eq(solution[a] + solution[b], 35.0);
+}
+
+#[test]
+fn test_concave_revenue_maximized() {
+    // Volume discounts on the selling price make revenue concave
+    let revenue = [(0.0, 0.0), (100.0, 500.0), (300.0, 1100.0)];
+
+    let mut model = Model::new();
+    let sold = model.add_variable().continuous();
+    let income = model.add_piecewise_linear(sold, &revenue).unwrap();
+    model.set_objective(Maximize, income - 3.5 * sold);
+
+    let solution = model.solve().unwrap();
+    // Selling past the first tier only earns 3 per unit
+    assert_approx_eq(solution[sold], 100.0);
+    assert_approx_eq(solution[income], 500.0);
+    assert_approx_eq(solution.objective_value().unwrap(), 150.0);
+}
+
+#[test]
+fn test_linear_function_in_any_direction() {
+    let mut model = Model::new();
+    let x = model.add_variable().continuous();
+    let y = model
+        .add_piecewise_linear(x, &[(1.0, 3.0), (2.0, 5.0), (4.0, 9.0)])
+        .unwrap();
+    model.set_objective(Maximize, y);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 4.0);
+    assert_approx_eq(solution[y], 9.0);
+}
+
+#[test]
+fn test_invalid_breakpoints_rejected() {
+    let mut model = Model::new();
+    let x = model.add_variable().continuous();
+
+    let error = model
+        .add_piecewise_linear(x, &[(0.0, 0.0), (0.0, 1.0)])
+        .unwrap_err();
+    assert!(matches!(error, SolverError::InvalidBreakpoints { .. }));
+    assert!(model
+        .add_piecewise_linear(x, &[(0.0, f64::NAN), (1.0, 1.0)])
+        .is_err());
+}
+
+#[test]
+fn test_convex_function_bounded_from_below() {
+    // The relaxation could reach y = 15 at x = 7.5 on the chord from (0, 0) to (20, 30)
+    let mut model = Model::new();
+    let x = model.add_variable().continuous();
+    let y = model
+        .add_piecewise_linear(x, &[(0.0, 0.0), (10.0, 10.0), (20.0, 30.0)])
+        .unwrap();
+    model.add_constraint(y).ge(15.0);
+    model.set_objective(Minimize, x);
+
+    let solution = model.solve().unwrap();
+    assert_eq!(solution.status(), &SolverStatus::Optimal);
+    assert_approx_eq(solution[x], 12.5);
+    assert_approx_eq(solution[y], 15.0);
+}
+
+#[test]
+fn test_concave_cost_minimized() {
+    // Volume discounts make the cost concave, so the cheapest units come last
+    let mut model = Model::new();
+    let x = model.add_variable().continuous();
+    let cost = model
+        .add_piecewise_linear(x, &[(0.0, 0.0), (10.0, 50.0), (20.0, 80.0)])
+        .unwrap();
+    model.add_constraint(x).ge(5.0);
+    model.set_objective(Minimize, cost + 1.0 * x);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 5.0);
+    assert_approx_eq(solution[cost], 25.0);
+    assert_approx_eq(solution.objective_value().unwrap(), 30.0);
+}
+
+#[test]
+fn test_non_convex_function() {
+    // f through (0, 0), (1, 2), (2, 2.5), (3, 5) is neither convex nor concave
+    let mut model = Model::new();
+    let x = model.add_variable().continuous();
+    let y = model
+        .add_piecewise_linear(x, &[(0.0, 0.0), (1.0, 2.0), (2.0, 2.5), (3.0, 5.0)])
+        .unwrap();
+    model.add_constraint(x).ge(1.5);
+    model.set_objective(Minimize, y);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 1.5);
+    assert_approx_eq(solution[y], 2.25);
+
+    // Maximizing the same function stays on it as well
+    model.add_constraint(x).le(2.5);
+    model.set_objective(Maximize, y - x);
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 2.5);
+    assert_approx_eq(solution[y], 3.75);
+}
+
+#[test]
+fn test_abs_deviation_minimized() {
+    let mut model = Model::new();
+    let x = model.add_variable().continuous();
+
+    // The sum of distances to 3, 4 and 7 is smallest at the median
+    let deviation = quicksum([3.0, 4.0, 7.0].iter().map(|&t| model.abs(x - t)));
+    model.set_objective(Minimize, deviation);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 4.0);
+    assert_approx_eq(solution.objective_value().unwrap(), 4.0);
+}
+
+#[test]
+fn test_max_and_min_of_expressions() {
+    // Split 12 hours of work over two machines, the second one half as fast
+    let mut model = Model::new();
+    let a = model.add_variable().non_negative().continuous();
+    let b = model.add_variable().non_negative().continuous();
+    model.add_constraint(a + b).eq(12.0);
+
+    let makespan = model.max_of([LinearExpr::from(a), 2.0 * b]);
+    model.set_objective(Minimize, makespan.clone());
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.eval(&makespan), 8.0);
+    assert_approx_eq(solution[a], 8.0);
+
+    // Maximize the smaller of two shares
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+    model.add_constraint(x + 2.0 * y).le(6.0);
+
+    let worst = model.min_of([x, y]);
+    model.set_objective(Maximize, worst);
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 2.0);
+    assert_approx_eq(solution[y], 2.0);
+}
+
+#[test]
+fn test_abs_in_constraint_and_maximized() {
+    // Staying at least 5 away from 3 leaves [0, 10] only from 8 on
+    let mut model = Model::new();
+    let x = model.add_variable().bounds(0.0..=10.0).continuous();
+    let distance = model.abs(x - 3.0);
+    model.add_constraint(distance.clone()).ge(5.0);
+    model.set_objective(Minimize, x);
+
+    let solution = model.solve().unwrap();
+    assert_eq!(solution.status(), &SolverStatus::Optimal);
+    assert_approx_eq(solution[x], 8.0);
+    assert_approx_eq(solution.eval(&distance), 5.0);
+
+    let mut model = Model::new();
+    let x = model.add_variable().bounds(-1.0..=2.0).continuous();
+    let distance = model.abs(x);
+    model.set_objective(Maximize, distance.clone());
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 2.0);
+    assert_approx_eq(solution.eval(&distance), 2.0);
+}
+
+#[test]
+fn test_max_and_min_of_in_constraints() {
+    // One of the two must reach 6, and the first one is cheaper
+    let mut model = Model::new();
+    let a = model.add_variable().bounds(0.0..=10.0).continuous();
+    let b = model.add_variable().bounds(0.0..=10.0).continuous();
+    let largest = model.max_of([a, b]);
+    model.add_constraint(largest).ge(6.0);
+    model.set_objective(Minimize, a + 2.0 * b);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[a], 6.0);
+    assert_approx_eq(solution[b], 0.0);
+
+    // Only one of the two may exceed 2
+    let mut model = Model::new();
+    let x = model.add_variable().bounds(0.0..=10.0).continuous();
+    let y = model.add_variable().bounds(0.0..=10.0).continuous();
+    let smallest = model.min_of([x, y]);
+    model.add_constraint(smallest.clone()).le(2.0);
+    model.set_objective(Maximize, x + y);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.objective_value().unwrap(), 12.0);
+    assert_approx_eq(solution.eval(&smallest), 2.0);
+}
@@ -0,0 +1,71 @@
+mod common;
+use common::assert_approx_eq;
+use rustplex::prelude::*;
+use slotmap::SecondaryMap;
+
+#[test]
+fn test_minimum_lot_size() {
+    // The own plant runs at least 20 units if it runs at all; buying costs `price` per unit
+    let solve = |price: f64| {
+        let mut model = Model::new();
+        let produce = model.add_variable().bounds(20.0..=100.0).semi_continuous();
+        let buy = model.add_variable().bounds(0.0..=100.0).continuous();
+        model.add_constraint(produce + buy).ge(10.0);
+        model.set_objective(Minimize, produce + price * buy);
+
+        let solution = model.solve().unwrap();
+        assert_eq!(solution.status(), &SolverStatus::Optimal);
+        assert!(model.check_solution(&solution).is_feasible(1e-9));
+        (solution[produce], solution[buy])
+    };
+
+    let (produce, buy) = solve(3.0);
+    assert_approx_eq(produce, 20.0);
+    assert_approx_eq(buy, 0.0);
+
+    let (produce, buy) = solve(1.5);
+    assert_approx_eq(produce, 0.0);
+    assert_approx_eq(buy, 10.0);
+}
+
+#[test]
+fn test_semi_integer() {
+    let mut model = Model::new();
+    let x = model.add_variable().bounds(3.0..=10.0).semi_integer();
+    model.add_constraint(2.0 * x).le(9.0);
+    model.set_objective(Maximize, x);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 4.0);
+
+    // Nothing between 0 and the lower bound
+    let mut model = Model::new();
+    let x = model.add_variable().bounds(3.0..=10.0).semi_integer();
+    model.add_constraint(2.0 * x).le(5.0);
+    model.set_objective(Maximize, x);
+
+    let solution = model.solve().unwrap();
+    assert_eq!(solution.status(), &SolverStatus::Optimal);
+    assert_approx_eq(solution[x], 0.0);
+}
+
+#[test]
+fn test_check_semi_continuous_values() {
+    let mut model = Model::new();
+    let x = model.add_variable().bounds(20.0..=100.0).semi_continuous();
+    let y = model.add_variable().bounds(2.0..=5.0).semi_integer();
+    model.set_objective(Maximize, x + y);
+
+    let check = |x_value: f64, y_value: f64| {
+        let mut values = SecondaryMap::new();
+        values.insert(x, x_value);
+        values.insert(y, y_value);
+        model.check_values(&values)
+    };
+
+    assert!(check(0.0, 0.0).is_feasible(1e-9));
+    assert!(check(50.0, 3.0).is_feasible(1e-9));
+    assert_approx_eq(check(5.0, 0.0).bound_violation(x).absolute, 5.0);
+    assert_approx_eq(check(15.0, 0.0).bound_violation(x).absolute, 5.0);
+    assert_approx_eq(check(0.0, 2.5).max_integrality_violation(), 0.5);
+}
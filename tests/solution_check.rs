@@ -0,0 +1,77 @@
+mod common;
+use common::assert_approx_eq;
+use rustplex::prelude::*;
+use slotmap::SecondaryMap;
+
+#[test]
+fn test_check_optimal_solution() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().bounds(1.0..=6.0).continuous();
+
+    model.set_objective(Minimize, 2.0 * x + 3.0 * y);
+    model.add_constraint(x + y).ge(8.0);
+    model.add_constraint(x - y).le(4.0);
+
+    let solution = model.solve().unwrap();
+    let check = model.check_solution(&solution);
+
+    assert!(check.is_feasible(1e-9));
+    assert!(check.is_optimal(1e-9));
+    assert_approx_eq(check.max_dual_infeasibility().unwrap(), 0.0);
+    assert_approx_eq(check.max_complementarity().unwrap(), 0.0);
+}
+
+#[test]
+fn test_check_external_plan() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().bounds(0.0..=5.0).integer();
+
+    model.set_objective(Maximize, x + y);
+    let capacity = model.add_constraint(x + 2.0 * y).le(10.0);
+    let demand = model.add_constraint(x).ge(1.0);
+
+    let mut plan = SecondaryMap::new();
+    plan.insert(x, 4.0);
+    plan.insert(y, 5.5);
+
+    let check = model.check_values(&plan);
+    assert!(!check.is_feasible(1e-9));
+    assert!(!check.is_optimal(1e-9));
+
+    // 4 + 2 * 5.5 = 15 exceeds the capacity of 10 by 5
+    let violation = check.constraint_violation(capacity);
+    assert_approx_eq(violation.absolute, 5.0);
+    assert_approx_eq(violation.relative, 5.0 / 11.0);
+    assert_approx_eq(check.constraint_violation(demand).absolute, 0.0);
+
+    assert_approx_eq(check.bound_violation(y).absolute, 0.5);
+    assert_approx_eq(check.bound_violation(x).absolute, 0.0);
+    assert_approx_eq(check.integrality_violations()[y], 0.5);
+    assert_approx_eq(check.max_primal_violation().absolute, 5.0);
+    assert!(check.max_dual_infeasibility().is_none());
+}
+
+#[test]
+fn test_check_values_without_duals() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, 3.0 * x + 4.0 * y);
+    model.add_constraint(x + 2.0 * y).le(14.0);
+    model.add_constraint(3.0 * x - y).le(0.0);
+    model.add_constraint(x - y).le(2.0);
+
+    let solution = model.solve().unwrap();
+    assert!(model.check_solution(&solution).is_optimal(1e-9));
+
+    // A feasible plan checks as feasible, but carries no dual information
+    let mut plan = solution.variable_values().clone().unwrap();
+    plan[x] = 1.0;
+    plan[y] = 3.0;
+    let check = model.check_values(&plan);
+    assert!(check.is_feasible(1e-9));
+    assert!(check.constraint_complementarity().is_none());
+}
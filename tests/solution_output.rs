@@ -0,0 +1,238 @@
+mod common;
+use common::assert_approx_eq;
+use rustplex::prelude::*;
+
+#[test]
+fn test_dual_values_maximization() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, 3.0 * x + 4.0 * y);
+    let c1 = model.add_constraint(x + 2.0 * y).le(14.0);
+    let c2 = model.add_constraint(3.0 * x - y).le(0.0);
+    let c3 = model.add_constraint(x - y).le(2.0);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.dual(c1), 15.0 / 7.0);
+    assert_approx_eq(solution.dual(c2), 2.0 / 7.0);
+    assert_approx_eq(solution.dual(c3), 0.0);
+    assert_approx_eq(solution.reduced_cost(x), 0.0);
+    assert_approx_eq(solution.reduced_cost(y), 0.0);
+}
+
+#[test]
+fn test_dual_values_minimization() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+    let z = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Minimize, 2.0 * x + 3.0 * y + 5.0 * z);
+    let demand = model.add_constraint(x + y + z).ge(10.0);
+    let cap_x = model.add_constraint(x).le(8.0);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.objective_value().unwrap(), 22.0);
+    // One more unit of demand is served by y
+    assert_approx_eq(solution.dual(demand), 3.0);
+    // One more unit of capacity for x replaces a unit of y
+    assert_approx_eq(solution.dual(cap_x), -1.0);
+    // Forcing a unit of z in costs 5 but saves a unit of y
+    assert_approx_eq(solution.reduced_cost(z), 2.0);
+}
+
+#[test]
+fn test_range_constraint_single_dual() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+    let z = model.add_variable().non_negative().continuous();
+
+    // The lower side of the range is binding
+    model.set_objective(Minimize, 2.0 * x + 3.0 * y + 5.0 * z);
+    let demand = model.add_constraint(x + y + z).between(10.0, 20.0);
+    let cap_x = model.add_constraint(x).le(8.0);
+    assert_eq!(model.constraints()[demand].range(), Some((10.0, 20.0)));
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.objective_value().unwrap(), 22.0);
+    assert_approx_eq(solution.dual(demand), 3.0);
+    assert_approx_eq(solution.dual(cap_x), -1.0);
+    assert_approx_eq(solution.reduced_cost(z), 2.0);
+    assert_approx_eq(solution.activity(demand), 10.0);
+    assert!(solution.is_binding(demand));
+    assert!(model.check_solution(&solution).is_optimal(1e-9));
+
+    // The upper side of the range is binding
+    model.set_objective(Maximize, 2.0 * x + 3.0 * y + 5.0 * z);
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.objective_value().unwrap(), 100.0);
+    assert_approx_eq(solution.dual(demand), 5.0);
+    assert_approx_eq(solution.slack(demand), 0.0);
+    assert!(model.check_solution(&solution).is_optimal(1e-9));
+
+    // Neither side is binding
+    let mid = model.add_constraint(z).range(0.0..=15.0);
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.objective_value().unwrap(), 90.0);
+    assert_approx_eq(solution.dual(mid), 2.0);
+    assert_approx_eq(solution.dual(demand), 3.0);
+    assert_approx_eq(solution.slack(mid), 0.0);
+}
+
+#[test]
+fn test_constraint_activity_and_slack() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    // Two machines: hours used per unit of x and y
+    model.set_objective(Maximize, 3.0 * x + 2.0 * y);
+    let machine_a = model.add_constraint(x + y).le(4.0);
+    let machine_b = model.add_constraint(x + 3.0 * y).le(6.0);
+    let minimum = model.add_constraint(x + 2.0).ge(y + 3.0);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 4.0);
+    assert_approx_eq(solution[y], 0.0);
+
+    assert_approx_eq(solution.activity(machine_a), 4.0);
+    assert_approx_eq(solution.slack(machine_a), 0.0);
+    assert!(solution.is_binding(machine_a));
+
+    assert_approx_eq(solution.activity(machine_b), 4.0);
+    assert_approx_eq(solution.slack(machine_b), 2.0);
+    assert!(!solution.is_binding(machine_b));
+
+    // Activity is the left hand side; the slack compares both sides
+    assert_approx_eq(solution.activity(minimum), 6.0);
+    assert_approx_eq(solution.slack(minimum), 3.0);
+}
+
+#[test]
+fn test_evaluate_expressions() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    let production_cost = 2.0 * x + 3.0 * y;
+    let shipping_cost = 0.5 * x + 10.0;
+    model.set_objective(Minimize, production_cost.clone() + shipping_cost.clone());
+    model.add_constraint(x + y).ge(10.0);
+    model.add_constraint(x).le(4.0);
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.eval(&production_cost), 26.0);
+    assert_approx_eq(solution.eval(&shipping_cost), 12.0);
+    assert_approx_eq(
+        solution.eval(&(production_cost.clone() + shipping_cost)),
+        solution.objective_value().unwrap(),
+    );
+
+    // Any assignment can be plugged in through a closure
+    let plan = |var| if var == x { 1.0 } else { 2.0 };
+    assert_approx_eq(production_cost.evaluate(plan), 8.0);
+}
+
+#[test]
+fn test_constraint_values_without_point() {
+    let mut model = Model::new();
+    let x = model.add_variable().non_negative().continuous();
+    model.set_objective(Maximize, x);
+    let c = model.add_constraint(x).ge(5.0);
+    model.add_constraint(x).le(3.0);
+
+    let solution = model.solve().unwrap();
+    assert!(solution.activities().is_none());
+    assert_approx_eq(solution.slack(c), 0.0);
+    assert!(!solution.is_binding(c));
+}
+
+#[test]
+fn test_write_solution_formats() {
+    let mut model = Model::new();
+    let x = model.add_variable().name("x").non_negative().continuous();
+    let y = model.add_variable().non_negative().continuous();
+
+    model.set_objective(Maximize, x + y);
+    model.add_constraint(3.0 * x + y).name("cap, main").le(1.0);
+
+    let solution = model.solve().unwrap();
+    let writer = model.solution_writer(&solution);
+
+    let mut sol = Vec::new();
+    writer.write(SolutionFormat::Sol, &mut sol).unwrap();
+    let sol = String::from_utf8(sol).unwrap();
+    assert!(sol.contains("# Status: Optimal"));
+    assert!(sol.contains("# Objective value = 1\n"));
+    assert!(sol.contains("\nx 0\n"));
+    assert!(sol.contains("\nx1 1\n"));
+    assert!(sol.contains("# Dual values\n\"cap, main\" 1\n"));
+
+    let mut csv = Vec::new();
+    writer.write(SolutionFormat::Csv, &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert!(csv.starts_with("kind,name,value,marginal\nstatus,Optimal,,\nobjective,,1,\n"));
+    assert!(csv.contains("variable,x,0,-2\n"));
+    assert!(csv.contains("constraint,\"cap, main\",,1\n"));
+
+    let mut json = Vec::new();
+    writer.write(SolutionFormat::Json, &mut json).unwrap();
+    let json = String::from_utf8(json).unwrap();
+    assert!(json.contains("\"status\": \"Optimal\""));
+    assert!(json.contains("{\"name\": \"x1\", \"value\": 1, \"reduced_cost\": 0}"));
+    assert!(json.contains("{\"name\": \"cap, main\", \"dual\": 1}"));
+}
+
+#[test]
+fn test_write_solution_names_are_unique() {
+    let mut model = Model::new();
+    // The second variable would be generated as `x1`, which the first one is named
+    let x = model
+        .add_variable()
+        .name("x1")
+        .bounds(0.0..=1.0)
+        .continuous();
+    let y = model.add_variable().bounds(0.0..=2.0).continuous();
+    let z = model
+        .add_variable()
+        .name("say \"hi\"")
+        .bounds(0.0..=3.0)
+        .continuous();
+
+    model.set_objective(Maximize, x + y + z);
+    let solution = model.solve().unwrap();
+
+    let mut sol = Vec::new();
+    model
+        .solution_writer(&solution)
+        .write_sol(&mut sol)
+        .unwrap();
+    let sol = String::from_utf8(sol).unwrap();
+    assert!(sol.contains("\nx1 1\n"));
+    assert!(sol.contains("\nx1_1 2\n"));
+    assert!(sol.contains("\n\"say \\\"hi\\\"\" 3\n"));
+}
+
+#[test]
+fn test_write_infeasible_solution() {
+    let mut model = Model::new();
+    let x = model.add_variable().name("x").non_negative().continuous();
+
+    model.set_objective(Maximize, x);
+    model.add_constraint(x).ge(5.0);
+    model.add_constraint(x).le(3.0);
+
+    let solution = model.solve().unwrap();
+
+    let mut json = Vec::new();
+    model
+        .solution_writer(&solution)
+        .write_json(&mut json)
+        .unwrap();
+    let json = String::from_utf8(json).unwrap();
+    assert!(json.contains("\"objective_value\": null"));
+    assert!(json.contains("{\"name\": \"x\", \"value\": null, \"reduced_cost\": null}"));
+    assert!(json.contains("{\"name\": \"c0\", \"dual\": null}"));
+}
@@ -0,0 +1,246 @@
+mod common;
+use common::assert_approx_eq;
+use rustplex::prelude::*;
+use rustplex::SosType;
+use slotmap::SecondaryMap;
+
+#[test]
+fn test_sos1_single_supplier() {
+    let mut model = Model::new();
+    let a = model.add_variable().bounds(0.0..=5.0).continuous();
+    let b = model.add_variable().bounds(0.0..=10.0).continuous();
+    let c = model.add_variable().bounds(0.0..=3.0).continuous();
+    model.add_sos1(&[a, b, c], &[1.0, 2.0, 3.0]).unwrap();
+    model.set_objective(Maximize, 3.0 * a + 2.0 * b + 4.0 * c);
+
+    let solution = model.solve().unwrap();
+    assert_eq!(solution.status(), &SolverStatus::Optimal);
+    assert_approx_eq(solution.objective_value().unwrap(), 20.0);
+    assert_approx_eq(solution[a], 0.0);
+    assert_approx_eq(solution[b], 10.0);
+    assert_approx_eq(solution[c], 0.0);
+}
+
+#[test]
+fn test_sos2_non_convex_piecewise_linear() {
+    // f through (0, 0), (1, 2), (2, 2.5), (3, 5) is neither convex nor concave
+    let points = [(0.0, 0.0), (1.0, 2.0), (2.0, 2.5), (3.0, 5.0)];
+
+    let mut model = Model::new();
+    let x = model.add_variable().continuous();
+    let y = model.add_variable().continuous();
+    let lambda: Vec<_> = points
+        .iter()
+        .map(|_| model.add_variable().non_negative().continuous())
+        .collect();
+    let weights: Vec<f64> = points.iter().map(|&(x_k, _)| x_k).collect();
+    let sos = model.add_sos2(&lambda, &weights).unwrap();
+    assert_eq!(model.sos_constraints()[sos].sos_type(), SosType::Sos2);
+
+    model
+        .add_constraint(quicksum(lambda.iter().copied()))
+        .eq(1.0);
+    let interpolate = |value: fn(&(f64, f64)) -> f64| {
+        quicksum(
+            points
+                .iter()
+                .zip(&lambda)
+                .map(|(point, &l)| value(point) * l),
+        )
+    };
+    model.add_constraint(x).eq(interpolate(|point| point.0));
+    model.add_constraint(y).eq(interpolate(|point| point.1));
+    model.add_constraint(x).ge(1.5);
+    model.set_objective(Minimize, y);
+
+    // The LP relaxation would interpolate (0, 0) and (2, 2.5) to reach 1.875
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution[x], 1.5);
+    assert_approx_eq(solution[y], 2.25);
+    assert!(
+        model
+            .check_solution(&solution)
+            .max_primal_violation()
+            .absolute
+            < 1e-9
+    );
+}
+
+#[test]
+fn test_sos1_infeasible() {
+    let mut model = Model::new();
+    let a = model.add_variable().bounds(0.0..=3.0).continuous();
+    let b = model.add_variable().bounds(0.0..=3.0).continuous();
+    model.add_sos1(&[a, b], &[1.0, 2.0]).unwrap();
+    model.add_constraint(a + b).ge(5.0);
+    model.set_objective(Minimize, a + b);
+
+    let solution = model.solve().unwrap();
+    assert_eq!(solution.status(), &SolverStatus::Infeasible);
+}
+
+#[test]
+fn test_invalid_sos_rejected() {
+    let mut model = Model::new();
+    let a = model.add_variable().continuous();
+    let b = model.add_variable().continuous();
+
+    let error = model.add_sos1(&[a, b], &[1.0, 1.0]).unwrap_err();
+    assert!(matches!(error, SolverError::InvalidSosWeights));
+    assert!(model.add_sos2(&[a, b], &[1.0]).is_err());
+    assert!(model.add_sos2(&[a, b], &[1.0, f64::NAN]).is_err());
+    let error = model.add_sos1(&[a, b, a], &[1.0, 2.0, 3.0]).unwrap_err();
+    assert!(matches!(error, SolverError::DuplicateSosVariable { variable } if variable == a));
+
+    let mut other = Model::new();
+    let foreign = other.add_variable().continuous();
+    let set = model.add_sos1(&[a, foreign], &[1.0, 2.0]).unwrap();
+    model.set_objective(Maximize, a + b);
+    let error = model.solve().unwrap_err();
+    assert!(
+        matches!(error, SolverError::UnknownSosVariable { set: s, variable } if s == set && variable == foreign)
+    );
+}
+
+#[test]
+fn test_check_sos_violations() {
+    let mut model = Model::new();
+    let a = model.add_variable().bounds(0.0..=5.0).continuous();
+    let b = model.add_variable().bounds(0.0..=5.0).continuous();
+    let c = model.add_variable().bounds(0.0..=5.0).continuous();
+    let one = model.add_sos1(&[a, b, c], &[1.0, 2.0, 3.0]).unwrap();
+    let two = model.add_sos2(&[a, b, c], &[1.0, 2.0, 3.0]).unwrap();
+    model.set_objective(Maximize, a + b + c);
+
+    let check = |values: [f64; 3]| {
+        let mut point = SecondaryMap::new();
+        for (var_key, value) in [a, b, c].into_iter().zip(values) {
+            point.insert(var_key, value);
+        }
+        model.check_values(&point)
+    };
+
+    let report = check([0.0, 2.0, 0.0]);
+    assert!(report.is_feasible(1e-9));
+    assert_approx_eq(report.max_sos_violation(), 0.0);
+
+    // Adjacent members satisfy the SOS2 set only
+    let report = check([0.0, 2.0, 3.0]);
+    assert_approx_eq(report.sos_violation(one), 2.0);
+    assert_approx_eq(report.sos_violation(two), 0.0);
+    assert!(!report.is_feasible(1e-9));
+
+    let report = check([1.0, 4.0, 3.0]);
+    assert_approx_eq(report.sos_violation(one), 4.0);
+    assert_approx_eq(report.sos_violation(two), 1.0);
+    assert_approx_eq(report.max_sos_violation(), 4.0);
+}
+
+#[test]
+fn test_branch_and_bound_events() {
+    let mut model = Model::new();
+    let a = model.add_variable().bounds(0.0..=5.0).continuous();
+    let b = model.add_variable().bounds(0.0..=10.0).continuous();
+    let c = model.add_variable().bounds(0.0..=3.0).continuous();
+    model.add_sos1(&[a, b, c], &[1.0, 2.0, 3.0]).unwrap();
+    model.set_objective(Minimize, -3.0 * a - 2.0 * b - 4.0 * c);
+
+    let mut events = Vec::new();
+    let solution = model
+        .solve_with_callback(&mut |event: &SolverEvent| {
+            events.push(*event);
+            CallbackAction::Continue
+        })
+        .unwrap();
+    assert_approx_eq(solution.objective_value().unwrap(), -20.0);
+
+    // Iterations count on across the nodes, and only the root reports its phases
+    let iterations: Vec<u32> = events
+        .iter()
+        .map(|event| match *event {
+            SolverEvent::PhaseChanged { iteration, .. }
+            | SolverEvent::Progress { iteration, .. }
+            | SolverEvent::NewIncumbent { iteration, .. }
+            | SolverEvent::NodeProcessed { iteration, .. } => iteration,
+        })
+        .collect();
+    assert!(iterations.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(*iterations.last().unwrap(), *solution.iterations());
+    let phase_changes = events
+        .iter()
+        .filter(|event| matches!(event, SolverEvent::PhaseChanged { .. }))
+        .count();
+    assert!(phase_changes <= 2);
+
+    let nodes: Vec<(u32, Option<f64>)> = events
+        .iter()
+        .filter_map(|event| match *event {
+            SolverEvent::NodeProcessed { node, bound, .. } => Some((node, bound)),
+            _ => None,
+        })
+        .collect();
+    assert!(nodes.len() > 1);
+    assert!(nodes
+        .iter()
+        .enumerate()
+        .all(|(i, &(node, _))| node == i as u32));
+    // The root relaxation bounds every solution from below
+    assert!(nodes[0].1.unwrap() <= -20.0);
+
+    match events.last().unwrap() {
+        SolverEvent::NodeProcessed {
+            incumbent,
+            open_nodes,
+            ..
+        } => {
+            assert_approx_eq(incumbent.unwrap(), -20.0);
+            assert_eq!(*open_nodes, 0);
+        }
+        event => panic!("Expected a node event, got {:?}", event),
+    }
+    let incumbents: Vec<f64> = events
+        .iter()
+        .filter_map(|event| match *event {
+            SolverEvent::NewIncumbent {
+                objective_value, ..
+            } => Some(objective_value),
+            _ => None,
+        })
+        .collect();
+    assert_approx_eq(*incumbents.last().unwrap(), -20.0);
+
+    // Stopping at the first incumbent keeps it
+    let solution = model
+        .solve_with_callback(&mut |event: &SolverEvent| match event {
+            SolverEvent::NewIncumbent { .. } => CallbackAction::Terminate,
+            _ => CallbackAction::Continue,
+        })
+        .unwrap();
+    assert_eq!(solution.status(), &SolverStatus::Interrupted);
+    assert!(solution.objective_value().is_some());
+}
+
+#[test]
+fn test_branch_and_bound_shares_iteration_budget() {
+    let build = |max_iterations| {
+        let mut model = Model::new();
+        model.set_max_iterations(max_iterations);
+        let a = model.add_variable().bounds(0.0..=5.0).continuous();
+        let b = model.add_variable().bounds(0.0..=10.0).continuous();
+        let c = model.add_variable().bounds(0.0..=3.0).continuous();
+        model.add_sos1(&[a, b, c], &[1.0, 2.0, 3.0]).unwrap();
+        model.set_objective(Minimize, -3.0 * a - 2.0 * b - 4.0 * c);
+        model.solve().unwrap()
+    };
+
+    let total = *build(10_000).iterations();
+    assert!(total > 1);
+
+    // The nodes only get the iterations the preceding ones left over
+    let limited = build(total - 1);
+    assert!(matches!(
+        limited.status(),
+        SolverStatus::MaxIterationsReached
+    ));
+    assert!(*limited.iterations() < total);
+}
@@ -76,3 +76,39 @@ fn test_epsilon_perturbation() {
     // Check if x is large (approx 10^9)
     assert!(solution[x] > 1_000_000.0);
 }
+
+/// Tiny Rate Coefficients
+/// 1e-12 * x + y <= 1 keeps its 1e-12 coefficient unless the drop tolerance is raised
+#[test]
+fn test_tiny_coefficients_follow_drop_tolerance() {
+    // The pivot tolerance must also admit the tiny rate as a pivot element
+    let mut model = Model::new().with_config(SolverConfig {
+        pivot_tolerance: 1e-13,
+        ..Default::default()
+    });
+    let x = model.add_variable().non_negative().continuous();
+    let y = model.add_variable().bounds(0.5..=1.0).continuous();
+
+    let expr = 1e-12 * x + y;
+    assert_eq!(expr.terms.len(), 2);
+
+    model.set_objective(Maximize, x);
+    model.add_constraint(expr).le(1.0);
+
+    // Optimal solution: y = 0.5, x = 0.5 / 1e-12
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    assert!((solution[x] / 5e11 - 1.0).abs() < 1e-6);
+
+    // With a coarser drop tolerance the rate is treated as zero
+    model.set_drop_tolerance(1e-10);
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Unbounded));
+
+    // Expressions added from now on are stored without the rate
+    let c = model.add_constraint(1e-12 * x + y).le(2.0 + 1e-12 * y);
+    assert_eq!(model.constraints()[c].lhs().terms, vec![(y, 1.0)]);
+    assert!(model.constraints()[c].rhs().terms.is_empty());
+    model.set_objective(Maximize, x + 1e-12 * y);
+    assert_eq!(model.objective().unwrap().expr().terms, vec![(x, 1.0)]);
+}
@@ -0,0 +1,133 @@
+mod common;
+use common::assert_approx_eq;
+use rustplex::prelude::*;
+use rustplex::VariableType;
+
+#[test]
+fn test_transportation_with_variable_array() {
+    let supply = [20.0, 30.0];
+    let demand = [10.0, 25.0, 15.0];
+    let cost = [[8.0, 6.0, 10.0], [9.0, 12.0, 13.0]];
+
+    let mut model = Model::new();
+    let x = model
+        .add_variable_array((supply.len(), demand.len()))
+        .name("x")
+        .non_negative()
+        .continuous();
+    assert_eq!(x.len(), 6);
+    assert_eq!(model.variables()[x[(1, 2)]].name(), "x[1,2]");
+
+    let mut objective = LinearExpr::new();
+    for (&(i, j), var) in x.iter() {
+        objective.add_term(var, cost[i][j]);
+    }
+    model.set_objective(Minimize, objective);
+
+    for (i, &amount) in supply.iter().enumerate() {
+        let mut shipped = LinearExpr::new();
+        for j in 0..demand.len() {
+            shipped.add_term(x[(i, j)], 1.0);
+        }
+        model.add_constraint(shipped).le(amount);
+    }
+    for (j, &amount) in demand.iter().enumerate() {
+        let mut received = LinearExpr::new();
+        for i in 0..supply.len() {
+            received.add_term(x[(i, j)], 1.0);
+        }
+        model.add_constraint(received).ge(amount);
+    }
+
+    let solution = model.solve().unwrap();
+    assert!(matches!(solution.status(), SolverStatus::Optimal));
+    // Plant 0 saves the most on market 1, so it ships its whole supply there
+    assert_approx_eq(solution[x[(0, 1)]], 20.0);
+    assert_approx_eq(solution.objective_value().unwrap(), 465.0);
+}
+
+#[test]
+fn test_variable_family_with_hashable_indices() {
+    let mut model = Model::new();
+    let products = ["bread", "cake", "bread"];
+    let make = model
+        .add_variables(products.iter().map(|p| p.to_string()))
+        .name("make")
+        .bounds(0.0..=5.0)
+        .integer();
+
+    // Repeated indices create a single variable
+    assert_eq!(make.len(), 2);
+    let cake = make.get(&"cake".to_string()).unwrap();
+    let variable = &model.variables()[cake];
+    assert_eq!(variable.name(), "make[cake]");
+    assert_eq!(variable.upper_bound(), 5.0);
+    assert!(matches!(variable.var_type(), VariableType::Integer));
+    assert!(make.get(&"pie".to_string()).is_none());
+
+    let indices: Vec<_> = make.indices().to_vec();
+    assert_eq!(indices, vec!["bread".to_string(), "cake".to_string()]);
+}
+
+#[test]
+fn test_unnamed_variable_family() {
+    let mut model = Model::new();
+    let y = model.add_variable_array(3).binary();
+    assert_eq!(y.keys().len(), 3);
+    assert_eq!(model.variables()[y[2]].name(), "<unnamed>");
+    assert_eq!(model.variables()[y[0]].upper_bound(), 1.0);
+}
+
+#[test]
+fn test_variable_family_with_custom_indices() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Plant {
+        North,
+        South,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Route {
+        from: Plant,
+        to: &'static str,
+    }
+
+    let mut model = Model::new();
+    let open = model
+        .add_variables([Plant::North, Plant::South])
+        .name_with(|plant| format!("open_{:?}", plant))
+        .bounds(0.0..=1.0)
+        .continuous();
+    let routes = [Plant::North, Plant::South].map(|from| Route { from, to: "city" });
+    let ship = model
+        .add_variables(routes.clone())
+        .bounds(0.0..=10.0)
+        .continuous();
+
+    assert_eq!(model.variables()[open[Plant::South]].name(), "open_South");
+    assert_eq!(
+        model.variables()[ship[routes[0].clone()]].name(),
+        "<unnamed>"
+    );
+
+    // Only open plants ship; the northern one is cheaper despite its opening cost
+    for &plant in &[Plant::North, Plant::South] {
+        let route = Route {
+            from: plant,
+            to: "city",
+        };
+        model.add_constraint(ship[route]).le(10.0 * open[plant]);
+    }
+    model
+        .add_constraint(ship[routes[0].clone()] + ship[routes[1].clone()])
+        .ge(8.0);
+    model.set_objective(
+        Minimize,
+        ship[routes[0].clone()] + 2.0 * ship[routes[1].clone()] + 5.0 * open[Plant::North],
+    );
+
+    let solution = model.solve().unwrap();
+    assert_approx_eq(solution.objective_value().unwrap(), 12.0);
+    assert_approx_eq(solution[open[Plant::North]], 0.8);
+    assert_approx_eq(solution[ship[routes[0].clone()]], 8.0);
+}